@@ -0,0 +1,126 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::ApiClient;
+use crate::utils::state;
+
+/// linkid 缓存文件名
+pub(super) const LINKID_CACHE_FILE: &str = "linkid_cache.json";
+
+/// 缓存有效期；linkid 可以修改，过期后重新查询
+const LINKID_CACHE_TTL_DAYS: i64 = 7;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedUser {
+    user_id: uuid::Uuid,
+    resolved_at: DateTime<Utc>,
+}
+
+/// 按服务地址分组的 linkid → 用户 ID 缓存
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct LinkidCache {
+    servers: BTreeMap<String, BTreeMap<String, CachedUser>>,
+}
+
+impl LinkidCache {
+    fn get(&self, server: &str, linkid: &str, now: DateTime<Utc>) -> Option<uuid::Uuid> {
+        self.servers
+            .get(server)?
+            .get(linkid)
+            .filter(|entry| now - entry.resolved_at < Duration::days(LINKID_CACHE_TTL_DAYS))
+            .map(|entry| entry.user_id)
+    }
+
+    fn insert(&mut self, server: &str, linkid: &str, user_id: uuid::Uuid, now: DateTime<Utc>) {
+        self.servers.entry(server.to_string()).or_default().insert(
+            linkid.to_string(),
+            CachedUser {
+                user_id,
+                resolved_at: now,
+            },
+        );
+    }
+}
+
+/// 规范化 linkid：去掉空白和 `@` 前缀
+fn normalize_linkid(value: &str) -> String {
+    value.trim().trim_start_matches('@').to_string()
+}
+
+impl ApiClient {
+    /// 将用户 ID 或 linkid（可带 `@` 前缀）解析为用户 ID，linkid 优先读取本地缓存，
+    /// 未命中时在已有会话的参与者中查找
+    pub async fn resolve_user_id(&self, linkid_or_id: &str) -> Result<uuid::Uuid> {
+        let value = linkid_or_id.trim();
+        if let Ok(user_id) = uuid::Uuid::parse_str(value) {
+            return Ok(user_id);
+        }
+
+        let linkid = normalize_linkid(value);
+        let now = Utc::now();
+        // 缓存读写失败不影响解析，退回在线查找
+        let mut cache: LinkidCache = self
+            .linkid_cache_path
+            .as_deref()
+            .and_then(|path| state::load_from(path).ok())
+            .unwrap_or_default();
+        if let Some(user_id) = cache.get(&self.base_url, &linkid, now) {
+            return Ok(user_id);
+        }
+
+        // 服务端没有按 linkid 查询用户的接口，从已有会话的参与者中查找
+        let conversations = self
+            .list_all_conversations()
+            .await
+            .with_context(|| format!("Failed to resolve linkid `{}`", value))?;
+        let Some(user_id) = conversations
+            .iter()
+            .flat_map(|conversation| &conversation.participants)
+            .find(|participant| participant.linkid.eq_ignore_ascii_case(&linkid))
+            .map(|participant| participant.user_id)
+        else {
+            anyhow::bail!(
+                "Cannot resolve linkid `{}`: no conversation includes this user. Use their user ID instead.",
+                value
+            );
+        };
+
+        cache.insert(&self.base_url, &linkid, user_id, now);
+        if let Some(path) = &self.linkid_cache_path {
+            let _ = state::save_to(path, &cache);
+        }
+        Ok(user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_entries_expire_and_are_scoped_by_server() {
+        let now = Utc::now();
+        let user_id = uuid::Uuid::new_v4();
+        let mut cache = LinkidCache::default();
+        cache.insert("https://a.example", "alice", user_id, now);
+
+        assert_eq!(cache.get("https://a.example", "alice", now), Some(user_id));
+        assert_eq!(cache.get("https://b.example", "alice", now), None);
+        assert_eq!(
+            cache.get(
+                "https://a.example",
+                "alice",
+                now + Duration::days(LINKID_CACHE_TTL_DAYS + 1)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_normalize_linkid_strips_prefix() {
+        assert_eq!(normalize_linkid(" @alice "), "alice");
+        assert_eq!(normalize_linkid("bob"), "bob");
+    }
+}
@@ -2,17 +2,27 @@ use agentlink_protocol::ApiResponse;
 use anyhow::{Context, Result};
 use reqwest::{Client, Method, RequestBuilder, Response};
 use serde::{de::DeserializeOwned, Serialize};
+use std::path::PathBuf;
 use std::time::Duration;
 
 use crate::config::Config;
 
 mod generated;
+mod linkid;
+
+/// 拉取全部会话时的每页条数
+const CONVERSATION_PAGE_SIZE: i64 = 50;
+
+/// 拉取全部会话时的页数上限，防止服务端分页异常导致死循环
+const MAX_CONVERSATION_PAGES: i64 = 200;
 
 #[derive(Debug, Clone)]
 pub struct ApiClient {
     client: Client,
     base_url: String,
     auth_token: Option<String>,
+    /// linkid 缓存文件，与当前配置文件位于同一目录
+    linkid_cache_path: Option<PathBuf>,
 }
 
 impl ApiClient {
@@ -30,6 +40,7 @@ impl ApiClient {
                 .runtime_api_key
                 .clone()
                 .or_else(|| config.api_key.clone()),
+            linkid_cache_path: config.state_file_path(linkid::LINKID_CACHE_FILE).ok(),
         })
     }
 
@@ -205,15 +216,45 @@ impl ApiClient {
     pub async fn list_notifications(
         &self,
         unread_only: bool,
+    ) -> Result<Vec<agentlink_protocol::message::NotificationResponse>> {
+        self.list_notifications_page(unread_only, None, None).await
+    }
+
+    pub async fn list_notifications_page(
+        &self,
+        unread_only: bool,
+        page: Option<i64>,
+        per_page: Option<i64>,
     ) -> Result<Vec<agentlink_protocol::message::NotificationResponse>> {
         self.get_notifications(agentlink_protocol::message::NotificationQuery {
             unread_only: Some(unread_only),
-            page: None,
-            per_page: None,
+            page,
+            per_page,
         })
         .await
     }
 
+    /// 逐页拉取全部会话，直到某一页不满
+    pub async fn list_all_conversations(
+        &self,
+    ) -> Result<Vec<agentlink_protocol::message::ConversationResponse>> {
+        let mut conversations = Vec::new();
+        for page in 1..=MAX_CONVERSATION_PAGES {
+            let batch = self
+                .list_conversations(agentlink_protocol::message::ConversationQuery {
+                    page: Some(page),
+                    per_page: Some(CONVERSATION_PAGE_SIZE),
+                })
+                .await?;
+            let done = (batch.len() as i64) < CONVERSATION_PAGE_SIZE;
+            conversations.extend(batch);
+            if done {
+                break;
+            }
+        }
+        Ok(conversations)
+    }
+
     pub async fn mark_notification_read(
         &self,
         notification_id: &str,
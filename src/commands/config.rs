@@ -16,7 +16,7 @@ pub enum ConfigCommands {
     ///   agentlink config set base_url https://api.example.com
     ///   agentlink config set api_key sk_xxx
     Set {
-        /// 配置键 (base_url, api_key, websocket_url, output_format, page_size)
+        /// 配置键 (base_url, api_key, websocket_url, output_format, page_size, inbox_task_query)
         key: String,
         /// 配置值
         value: String,
@@ -75,6 +75,14 @@ pub async fn execute(command: ConfigCommands, config: &mut Config) -> Result<()>
             println!("{}", "Defaults:".bold());
             println!("  {}: {}", "Output Format".bold(), config.defaults.output_format);
             println!("  {}: {}", "Page Size".bold(), config.defaults.page_size);
+
+            println!();
+            println!("{}", "Inbox:".bold());
+            println!(
+                "  {}: {}",
+                "Task Query".bold(),
+                config.inbox.task_query.as_deref().unwrap_or("Not set")
+            );
             Ok(())
         }
 
@@ -105,10 +113,16 @@ pub async fn execute(command: ConfigCommands, config: &mut Config) -> Result<()>
                     config.save()?;
                     println!("{} Default page size updated.", "✓".green());
                 }
+                "inbox_task_query" => {
+                    let value = value.trim().to_string();
+                    config.inbox.task_query = (!value.is_empty()).then_some(value);
+                    config.save()?;
+                    println!("{} Inbox task query updated.", "✓".green());
+                }
                 _ => {
                     println!("{} Unknown configuration key: {}", "✗".red(), key);
                     println!(
-                        "Available keys: base_url, api_key, websocket_url, output_format, page_size"
+                        "Available keys: base_url, api_key, websocket_url, output_format, page_size, inbox_task_query"
                     );
                 }
             }
@@ -127,6 +141,10 @@ pub async fn execute(command: ConfigCommands, config: &mut Config) -> Result<()>
                 "websocket_url" | "ws" => println!("{}", config.websocket_url),
                 "output_format" | "format" => println!("{}", config.defaults.output_format),
                 "page_size" => println!("{}", config.defaults.page_size),
+                "inbox_task_query" => println!(
+                    "{}",
+                    config.inbox.task_query.as_deref().unwrap_or("Not set")
+                ),
                 _ => {
                     println!("{} Unknown configuration key: {}", "✗".red(), key);
                     println!("Run `agentlink config list` to see available keys.");
@@ -149,6 +167,9 @@ pub async fn execute(command: ConfigCommands, config: &mut Config) -> Result<()>
             println!("  {} - Default output format (table, json, yaml, plain)", "output_format".cyan());
             println!("  {} - Default page size for list commands", "page_size".cyan());
             println!();
+            println!("{}:", "Inbox".bold());
+            println!("  {} - Task search shown as new tasks in `agentlink inbox` (empty to disable)", "inbox_task_query".cyan());
+            println!();
             println!("{}", "Configuration Priority:".bold().underline());
             println!("  1. CLI arguments (highest)");
             println!("  2. Config file");
@@ -0,0 +1,217 @@
+use colored::Colorize;
+use std::fmt::Display;
+
+use super::{print_feed_item, truncate};
+use agentlink_protocol::feed_v2::{ContentData, FeedItemV2};
+
+/// 详情展示中列表字段最多显示的条目数
+const MAX_LIST_ENTRIES: usize = 10;
+
+/// 详情中长文本的最大字符数
+const MAX_TEXT_CHARS: usize = 400;
+
+/// 打印动态的完整内容：摘要行之后逐项列出内容字段
+pub fn print_feed_item_detail(prefix: &str, item: &FeedItemV2) {
+    print_feed_item(prefix, item);
+
+    for (label, value) in describe(item) {
+        let mut lines = value.lines();
+        if let Some(first) = lines.next() {
+            println!("   {}: {}", label.bold(), first);
+        }
+        for line in lines {
+            println!("     {}", line);
+        }
+    }
+
+    println!("   {}: {}", "ID".dimmed(), item.id.to_string().dimmed());
+    println!();
+}
+
+/// 按内容类型列出 (标签, 值)；空字段会被跳过
+fn describe(item: &FeedItemV2) -> Vec<(&'static str, String)> {
+    let mut fields = Vec::new();
+    let mut push = |label: &'static str, value: Option<String>| {
+        if let Some(value) = value.filter(|value| !value.is_empty()) {
+            fields.push((label, value));
+        }
+    };
+
+    match &item.content_data {
+        ContentData::Post(post) => {
+            push("Content", item.content.clone());
+            push(
+                "Visibility",
+                Some(format!("{:?}", post.visibility).to_lowercase()),
+            );
+            push("Tags", join_list(&post.tags));
+            push("Media", join_list(&post.media_urls));
+        }
+        ContentData::Task(task) => {
+            push("Title", item.title.clone());
+            push(
+                "Budget",
+                amount_range(
+                    task.budget_min.as_ref(),
+                    task.budget_max.as_ref(),
+                    &task.currency,
+                ),
+            );
+            push("Type", Some(format!("{:?}", task.task_type).to_lowercase()));
+            push("Location", Some(task.location_type.to_string()));
+            push(
+                "Deadline",
+                task.deadline
+                    .map(|deadline| deadline.format("%Y-%m-%d %H:%M").to_string()),
+            );
+            push(
+                "Skills",
+                join_list(
+                    &task
+                        .skills
+                        .iter()
+                        .map(|skill| skill.name.clone())
+                        .collect::<Vec<_>>(),
+                ),
+            );
+            push("Applications", Some(task.application_count.to_string()));
+            push(
+                "Description",
+                Some(truncate(&task.description, MAX_TEXT_CHARS)),
+            );
+        }
+        ContentData::UserCard(card) => {
+            push(
+                "Name",
+                card.display_name
+                    .clone()
+                    .or_else(|| Some(card.linkid.clone())),
+            );
+            push("Headline", card.headline.clone());
+            push("Type", Some(card.user_type.to_string()));
+            push("Skills", join_list(&card.skills));
+            push(
+                "Bio",
+                card.bio.as_deref().map(|bio| truncate(bio, MAX_TEXT_CHARS)),
+            );
+        }
+        ContentData::AgentOffer(offer) => {
+            push("Offer", Some(offer.title.clone()));
+            push(
+                "Description",
+                offer
+                    .description
+                    .as_deref()
+                    .map(|description| truncate(description, MAX_TEXT_CHARS)),
+            );
+            push(
+                "Services",
+                non_empty_lines(
+                    offer
+                        .services
+                        .iter()
+                        .take(MAX_LIST_ENTRIES)
+                        .map(|service| {
+                            let currency = service.currency.as_deref().unwrap_or_default();
+                            let price = service.price.as_ref();
+                            match amount_range(price, price, currency) {
+                                Some(price) => format!("• {} — {}", service.name, price),
+                                None => format!("• {}", service.name),
+                            }
+                        })
+                        .collect(),
+                ),
+            );
+            push("Skills", join_list(&offer.skills));
+        }
+        ContentData::System(system) => {
+            push("Body", system.body.clone());
+            push("Link", system.action_url.clone());
+        }
+        // 新增的内容类型：展示原始负载
+        other => {
+            push("Payload", serde_json::to_string_pretty(other).ok());
+        }
+    }
+
+    fields
+}
+
+/// 金额或金额区间，带币种；上下限都缺失时返回 None
+fn amount_range<T: Display + PartialEq>(
+    min: Option<T>,
+    max: Option<T>,
+    currency: &str,
+) -> Option<String> {
+    let amount = match (min, max) {
+        (Some(min), Some(max)) if min == max => min.to_string(),
+        (Some(min), Some(max)) => format!("{}-{}", min, max),
+        (Some(min), None) => format!("{}+", min),
+        (None, Some(max)) => format!("up to {}", max),
+        (None, None) => return None,
+    };
+
+    Some(format!("{} {}", amount, currency).trim_end().to_string())
+}
+
+/// 逗号连接列表，超出上限的部分以数量表示
+fn join_list<T: AsRef<str>>(items: &[T]) -> Option<String> {
+    if items.is_empty() {
+        return None;
+    }
+
+    let mut joined = items
+        .iter()
+        .take(MAX_LIST_ENTRIES)
+        .map(AsRef::as_ref)
+        .collect::<Vec<_>>()
+        .join(", ");
+    if items.len() > MAX_LIST_ENTRIES {
+        joined.push_str(&format!(" (+{} more)", items.len() - MAX_LIST_ENTRIES));
+    }
+    Some(joined)
+}
+
+fn non_empty_lines(lines: Vec<String>) -> Option<String> {
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_amount_range_formats_bounds_and_currency() {
+        assert_eq!(
+            amount_range(Some(50), Some(80), "USD").as_deref(),
+            Some("50-80 USD")
+        );
+        assert_eq!(
+            amount_range(Some(10), Some(10), "USD").as_deref(),
+            Some("10 USD")
+        );
+        assert_eq!(
+            amount_range(Some(50), None, "USD").as_deref(),
+            Some("50+ USD")
+        );
+        assert_eq!(
+            amount_range(None, Some(80), "").as_deref(),
+            Some("up to 80")
+        );
+        assert_eq!(amount_range::<u32>(None, None, "USD"), None);
+    }
+
+    #[test]
+    fn test_join_list_caps_entries() {
+        let tags: Vec<String> = (0..MAX_LIST_ENTRIES + 2).map(|i| i.to_string()).collect();
+        let joined = join_list(&tags).unwrap();
+
+        assert!(joined.starts_with("0, 1, 2"));
+        assert!(joined.ends_with("(+2 more)"));
+        assert_eq!(join_list::<String>(&[]), None);
+    }
+}
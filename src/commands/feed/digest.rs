@@ -0,0 +1,499 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use super::FeedFilterArgs;
+use crate::api::ApiClient;
+use crate::config::Config;
+use crate::utils::output::print_success;
+use crate::utils::time::format_duration_short;
+use agentlink_protocol::feed_v2::{FeedItemV2, ItemType as FeedItemTypeV2};
+
+/// 摘要拉取时的每页条数
+const DIGEST_PAGE_SIZE: i64 = 50;
+
+/// 摘要参数
+pub struct DigestOptions {
+    pub filters: FeedFilterArgs,
+    pub since: chrono::Duration,
+    pub out: Option<PathBuf>,
+    pub html: bool,
+    pub top: usize,
+    pub max_pages: i64,
+}
+
+/// 摘要中的一条动态，只保留排名与展示需要的字段
+#[derive(Debug, Clone, PartialEq)]
+struct DigestEntry {
+    kind: String,
+    author: String,
+    title: String,
+    created_at: DateTime<Utc>,
+    likes: u64,
+    comments: u64,
+    shares: u64,
+}
+
+impl DigestEntry {
+    fn from_item(item: &FeedItemV2) -> Self {
+        let author = if item.author.display_name.is_empty() {
+            &item.author.linkid
+        } else {
+            &item.author.display_name
+        };
+        let count = |value| u64::try_from(value).unwrap_or(0);
+
+        Self {
+            kind: kind_name(&item.item_type),
+            author: author.clone(),
+            title: item
+                .title
+                .as_deref()
+                .or(item.content.as_deref())
+                .filter(|value| !value.trim().is_empty())
+                .map(|value| single_line(value, 120))
+                .unwrap_or_else(|| "(untitled)".to_string()),
+            created_at: item.created_at,
+            likes: count(item.engagement.like_count),
+            comments: count(item.engagement.comment_count),
+            shares: count(item.engagement.share_count),
+        }
+    }
+
+    /// 排名分数：评论与转发比点赞更能说明互动程度
+    fn score(&self) -> u64 {
+        self.likes + self.comments * 2 + self.shares * 3
+    }
+}
+
+/// 整理后的摘要内容
+#[derive(Debug)]
+struct Digest {
+    generated_at: DateTime<Utc>,
+    window: chrono::Duration,
+    total: usize,
+    counts_by_kind: BTreeMap<String, usize>,
+    top_posts: Vec<DigestEntry>,
+    new_tasks: Vec<DigestEntry>,
+    other_highlights: Vec<DigestEntry>,
+    active_authors: Vec<(String, usize, u64)>,
+}
+
+impl Digest {
+    fn build(
+        entries: Vec<DigestEntry>,
+        window: chrono::Duration,
+        generated_at: DateTime<Utc>,
+        top: usize,
+    ) -> Self {
+        let mut counts_by_kind = BTreeMap::new();
+        let mut authors: BTreeMap<String, (usize, u64)> = BTreeMap::new();
+        for entry in &entries {
+            *counts_by_kind.entry(entry.kind.clone()).or_insert(0) += 1;
+            let author = authors.entry(entry.author.clone()).or_insert((0, 0));
+            author.0 += 1;
+            author.1 += entry.score();
+        }
+
+        let ranked = |kind_filter: &dyn Fn(&str) -> bool| {
+            let mut selected: Vec<DigestEntry> = entries
+                .iter()
+                .filter(|entry| kind_filter(&entry.kind))
+                .cloned()
+                .collect();
+            selected.sort_by(|a, b| b.score().cmp(&a.score()));
+            selected.truncate(top);
+            selected
+        };
+
+        let top_posts = ranked(&|kind| kind == "post");
+        let other_highlights = ranked(&|kind| kind != "post" && kind != "task");
+
+        let mut new_tasks: Vec<DigestEntry> = entries
+            .iter()
+            .filter(|entry| entry.kind == "task")
+            .cloned()
+            .collect();
+        new_tasks.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        new_tasks.truncate(top);
+
+        let mut active_authors: Vec<(String, usize, u64)> = authors
+            .into_iter()
+            .map(|(author, (items, score))| (author, items, score))
+            .collect();
+        active_authors.sort_by(|a, b| b.1.cmp(&a.1).then(b.2.cmp(&a.2)));
+        active_authors.truncate(top);
+
+        Self {
+            generated_at,
+            window,
+            total: entries.len(),
+            counts_by_kind,
+            top_posts,
+            new_tasks,
+            other_highlights,
+            active_authors,
+        }
+    }
+}
+
+pub async fn run(client: &ApiClient, config: &Config, options: DigestOptions) -> Result<()> {
+    let now = Utc::now();
+    let cutoff = now - options.since;
+    let mut entries = Vec::new();
+    let mut fetch_error = None;
+
+    // 技能名称只需解析一次
+    let base_query = options
+        .filters
+        .to_query(client, config, None, Some(DIGEST_PAGE_SIZE))
+        .await?;
+
+    for page in 1..=options.max_pages.max(1) {
+        let mut query = base_query.clone();
+        query.page = Some(page);
+
+        let data = match client.get_feed(query).await {
+            Ok(data) => data,
+            // 第一页就失败时没有可写的内容
+            Err(error) if page == 1 => return Err(error.context("Failed to fetch feed")),
+            // 之后的页失败时先写出已拉取的部分，再以错误退出
+            Err(error) => {
+                eprintln!(
+                    "{}",
+                    format!(
+                        "! Failed to fetch feed page {}: {:#}; writing a partial digest.",
+                        page, error
+                    )
+                    .yellow()
+                );
+                fetch_error = Some(page);
+                break;
+            }
+        };
+
+        let page_len = data.items.len();
+        let mut reached_cutoff = true;
+        for item in data.items.iter().filter(|item| item.created_at >= cutoff) {
+            reached_cutoff = false;
+            entries.push(DigestEntry::from_item(item));
+        }
+
+        if (page_len as i64) < DIGEST_PAGE_SIZE || reached_cutoff {
+            break;
+        }
+    }
+
+    let digest = Digest::build(entries, options.since, now, options.top);
+    let html = options.html
+        || options.out.as_ref().is_some_and(|path| {
+            path.extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| {
+                    extension.eq_ignore_ascii_case("html") || extension.eq_ignore_ascii_case("htm")
+                })
+        });
+    let rendered = if html {
+        render_html(&digest)
+    } else {
+        render_markdown(&digest)
+    };
+
+    match &options.out {
+        Some(path) => {
+            std::fs::write(path, rendered)
+                .with_context(|| format!("Failed to write digest: {:?}", path))?;
+            print_success(&format!(
+                "Wrote digest of {} feed item(s) to {}",
+                digest.total,
+                path.display()
+            ));
+            if digest.total == 0 {
+                println!("{}", "No feed items fell inside the window.".yellow());
+            }
+        }
+        None => print!("{}", rendered),
+    }
+
+    if let Some(page) = fetch_error {
+        anyhow::bail!(
+            "The digest is incomplete: fetching page {} of the feed failed.",
+            page
+        );
+    }
+    Ok(())
+}
+
+fn render_markdown(digest: &Digest) -> String {
+    let mut out = String::new();
+    out.push_str("# AgentLink Feed Digest\n\n");
+    out.push_str(&format!(
+        "_Last {} · generated {} · {} item(s)_\n\n",
+        format_duration_short(digest.window),
+        digest.generated_at.format("%Y-%m-%d %H:%M UTC"),
+        digest.total
+    ));
+
+    if !digest.counts_by_kind.is_empty() {
+        out.push_str("## Overview\n\n| Type | Items |\n| --- | ---: |\n");
+        for (kind, count) in &digest.counts_by_kind {
+            out.push_str(&format!("| {} | {} |\n", kind, count));
+        }
+        out.push('\n');
+    }
+
+    let sections = [
+        ("Top Posts", &digest.top_posts),
+        ("New Tasks", &digest.new_tasks),
+        ("Other Highlights", &digest.other_highlights),
+    ];
+    for (heading, entries) in sections {
+        if entries.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("## {}\n\n", heading));
+        for (index, entry) in entries.iter().enumerate() {
+            out.push_str(&format!(
+                "{}. **{}** — {} ({})\n",
+                index + 1,
+                escape_markdown(&entry.author),
+                escape_markdown(&entry.title),
+                engagement_summary(entry)
+            ));
+        }
+        out.push('\n');
+    }
+
+    if !digest.active_authors.is_empty() {
+        out.push_str(
+            "## Active Authors\n\n| Author | Items | Engagement |\n| --- | ---: | ---: |\n",
+        );
+        for (author, items, score) in &digest.active_authors {
+            out.push_str(&format!(
+                "| {} | {} | {} |\n",
+                escape_markdown(author),
+                items,
+                score
+            ));
+        }
+        out.push('\n');
+    }
+
+    if digest.total == 0 {
+        out.push_str("No feed activity in this window.\n");
+    }
+
+    out
+}
+
+fn render_html(digest: &Digest) -> String {
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str("<title>AgentLink Feed Digest</title>\n</head>\n<body>\n");
+    out.push_str("<h1>AgentLink Feed Digest</h1>\n");
+    out.push_str(&format!(
+        "<p><em>Last {} · generated {} · {} item(s)</em></p>\n",
+        format_duration_short(digest.window),
+        digest.generated_at.format("%Y-%m-%d %H:%M UTC"),
+        digest.total
+    ));
+
+    if !digest.counts_by_kind.is_empty() {
+        out.push_str("<h2>Overview</h2>\n<table>\n<tr><th>Type</th><th>Items</th></tr>\n");
+        for (kind, count) in &digest.counts_by_kind {
+            out.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td></tr>\n",
+                escape_html(kind),
+                count
+            ));
+        }
+        out.push_str("</table>\n");
+    }
+
+    let sections = [
+        ("Top Posts", &digest.top_posts),
+        ("New Tasks", &digest.new_tasks),
+        ("Other Highlights", &digest.other_highlights),
+    ];
+    for (heading, entries) in sections {
+        if entries.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("<h2>{}</h2>\n<ol>\n", heading));
+        for entry in entries {
+            out.push_str(&format!(
+                "<li><strong>{}</strong> — {} ({})</li>\n",
+                escape_html(&entry.author),
+                escape_html(&entry.title),
+                engagement_summary(entry)
+            ));
+        }
+        out.push_str("</ol>\n");
+    }
+
+    if !digest.active_authors.is_empty() {
+        out.push_str("<h2>Active Authors</h2>\n<table>\n");
+        out.push_str("<tr><th>Author</th><th>Items</th><th>Engagement</th></tr>\n");
+        for (author, items, score) in &digest.active_authors {
+            out.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                escape_html(author),
+                items,
+                score
+            ));
+        }
+        out.push_str("</table>\n");
+    }
+
+    if digest.total == 0 {
+        out.push_str("<p>No feed activity in this window.</p>\n");
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn engagement_summary(entry: &DigestEntry) -> String {
+    format!(
+        "{} likes, {} comments, {} shares",
+        entry.likes, entry.comments, entry.shares
+    )
+}
+
+/// 动态类型的展示名，与 `--type` 参数一致
+fn kind_name(item_type: &FeedItemTypeV2) -> String {
+    match item_type {
+        FeedItemTypeV2::Post => "post".to_string(),
+        FeedItemTypeV2::Task => "task".to_string(),
+        FeedItemTypeV2::UserCard => "user_card".to_string(),
+        FeedItemTypeV2::AgentOffer => "agent_offer".to_string(),
+        FeedItemTypeV2::System => "system".to_string(),
+        // 新增的类型按变体名转换
+        other => normalize_kind(&format!("{:?}", other)),
+    }
+}
+
+/// 将 `UserCard` 这类变体名转换为 `user_card`
+fn normalize_kind(kind: &str) -> String {
+    let mut normalized = String::with_capacity(kind.len() + 2);
+    for (index, ch) in kind.chars().enumerate() {
+        if ch.is_uppercase() {
+            if index > 0 && !normalized.ends_with('_') {
+                normalized.push('_');
+            }
+            normalized.extend(ch.to_lowercase());
+        } else {
+            normalized.push(ch);
+        }
+    }
+    normalized
+}
+
+/// 折叠换行并按字符截断
+fn single_line(value: &str, max_chars: usize) -> String {
+    let collapsed = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= max_chars {
+        collapsed
+    } else {
+        format!(
+            "{}...",
+            collapsed.chars().take(max_chars).collect::<String>()
+        )
+    }
+}
+
+fn escape_markdown(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        if matches!(
+            ch,
+            '\\' | '*' | '_' | '`' | '[' | ']' | '|' | '<' | '>' | '#'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn entry(kind: &str, author: &str, title: &str, likes: u64) -> DigestEntry {
+        DigestEntry {
+            kind: kind.to_string(),
+            author: author.to_string(),
+            title: title.to_string(),
+            created_at: Utc.with_ymd_and_hms(2026, 1, 2, 3, 4, 5).unwrap(),
+            likes,
+            comments: 0,
+            shares: 0,
+        }
+    }
+
+    #[test]
+    fn test_kind_name_matches_type_arguments() {
+        assert_eq!(kind_name(&FeedItemTypeV2::UserCard), "user_card");
+        assert_eq!(kind_name(&FeedItemTypeV2::AgentOffer), "agent_offer");
+        assert_eq!(kind_name(&FeedItemTypeV2::Task), "task");
+        assert_eq!(normalize_kind("NewKind"), "new_kind");
+    }
+
+    #[test]
+    fn test_entry_score_weights_comments_and_shares() {
+        let mut entry = entry("post", "alice", "hello", 3);
+        entry.comments = 2;
+        entry.shares = 1;
+        assert_eq!(entry.score(), 3 + 4 + 3);
+    }
+
+    #[test]
+    fn test_digest_ranks_posts_and_counts_authors() {
+        let digest = Digest::build(
+            vec![
+                entry("post", "alice", "quiet", 1),
+                entry("post", "bob", "popular", 9),
+                entry("task", "alice", "Review PR", 0),
+            ],
+            chrono::Duration::hours(24),
+            Utc::now(),
+            5,
+        );
+
+        assert_eq!(digest.top_posts[0].title, "popular");
+        assert_eq!(digest.new_tasks.len(), 1);
+        assert_eq!(digest.active_authors[0].0, "alice");
+        assert_eq!(digest.counts_by_kind.get("post"), Some(&2));
+    }
+
+    #[test]
+    fn test_render_markdown_and_html_escape_content() {
+        let digest = Digest::build(
+            vec![entry("post", "a_b", "<script>*x*</script>", 2)],
+            chrono::Duration::hours(24),
+            Utc::now(),
+            5,
+        );
+
+        let markdown = render_markdown(&digest);
+        assert!(markdown.contains("## Top Posts"));
+        assert!(markdown.contains("**a\\_b**"));
+        assert!(markdown.contains("\\*x\\*"));
+
+        let html = render_html(&digest);
+        assert!(html.contains("&lt;script&gt;"));
+        assert!(!html.contains("<script>"));
+    }
+}
@@ -0,0 +1,758 @@
+use anyhow::Result;
+use clap::{Args, Subcommand, ValueEnum};
+use colored::Colorize;
+
+use crate::api::ApiClient;
+use crate::commands::skills::SkillCatalog;
+use crate::config::Config;
+use agentlink_protocol::feed_v2::{
+    ItemType as FeedItemTypeV2, FeedQueryV2, FeedDataV2, FeedItemV2, ContentData,
+};
+use agentlink_protocol::UserType;
+use crate::utils::output::print_error;
+use crate::utils::time::{parse_duration, parse_interval};
+use uuid::Uuid;
+
+pub mod detail;
+pub mod digest;
+pub mod tail;
+
+/// `feed show` 查找时的每页条数
+const SHOW_SCAN_PAGE_SIZE: i64 = 50;
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum FeedItemTypeArg {
+    All,
+    Post,
+    Task,
+    User,
+    AgentOffer,
+    System,
+}
+
+impl FeedItemTypeArg {
+    fn into_protocol(self) -> Option<FeedItemTypeV2> {
+        match self {
+            Self::All => None,
+            Self::Post => Some(FeedItemTypeV2::Post),
+            Self::Task => Some(FeedItemTypeV2::Task),
+            Self::User => Some(FeedItemTypeV2::UserCard),
+            Self::AgentOffer => Some(FeedItemTypeV2::AgentOffer),
+            Self::System => Some(FeedItemTypeV2::System),
+        }
+    }
+
+    /// 将多个类型参数转换为协议类型列表；包含 `all` 或为空时不做限制
+    fn list_into_protocol(values: &[Self]) -> Option<Vec<FeedItemTypeV2>> {
+        if values.is_empty() || values.contains(&Self::All) {
+            return None;
+        }
+
+        non_empty(values.iter().filter_map(|value| value.into_protocol()).collect())
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum AuthorTypeArg {
+    Agent,
+    Human,
+}
+
+impl From<AuthorTypeArg> for UserType {
+    fn from(value: AuthorTypeArg) -> Self {
+        match value {
+            AuthorTypeArg::Agent => UserType::Agent,
+            AuthorTypeArg::Human => UserType::Human,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum TimeRangeArg {
+    #[value(name = "1h")]
+    Hour,
+    #[value(name = "24h")]
+    Day,
+    #[value(name = "7d")]
+    Week,
+    #[value(name = "30d")]
+    Month,
+}
+
+impl TimeRangeArg {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Hour => "1h",
+            Self::Day => "24h",
+            Self::Week => "7d",
+            Self::Month => "30d",
+        }
+    }
+}
+
+/// 动态流过滤条件
+#[derive(Args, Clone, Debug, Default)]
+pub struct FeedFilterArgs {
+    /// 仅显示已关注对象的内容
+    #[arg(long)]
+    following: bool,
+
+    /// 内容类型，可重复指定（例如 --type task --type post）
+    #[arg(long = "type", value_enum)]
+    item_types: Vec<FeedItemTypeArg>,
+
+    /// 排除的内容类型，可重复指定
+    #[arg(long = "exclude-type", value_enum)]
+    exclude_types: Vec<FeedItemTypeArg>,
+
+    /// 内容子类型，可重复指定
+    #[arg(long = "subtype")]
+    item_subtypes: Vec<String>,
+
+    /// 优先排序的内容子类型，可重复指定
+    #[arg(long = "prefer-subtype")]
+    preferred_subtypes: Vec<String>,
+
+    /// 作者类型，可重复指定
+    #[arg(long = "author-type", value_enum)]
+    author_types: Vec<AuthorTypeArg>,
+
+    /// 作者 linkid 或用户 ID，可重复指定
+    #[arg(long = "author")]
+    authors: Vec<String>,
+
+    /// 标签，可重复指定
+    #[arg(long = "tag")]
+    tags: Vec<String>,
+
+    /// 技能名称或 ID，可重复指定
+    #[arg(long = "skill")]
+    skills: Vec<String>,
+
+    /// 时间范围
+    #[arg(long = "time-range", value_enum)]
+    time_range: Option<TimeRangeArg>,
+
+    /// 地点
+    #[arg(long)]
+    location: Option<String>,
+
+    /// 地点半径（公里），需配合 --location
+    #[arg(long = "radius-km", requires = "location")]
+    radius_km: Option<f64>,
+
+    /// 排除已浏览过的内容
+    #[arg(long)]
+    exclude_viewed: bool,
+
+    /// 不包含系统消息
+    #[arg(long)]
+    no_system: bool,
+
+    /// 搜索关键词
+    #[arg(short, long)]
+    q: Option<String>,
+}
+
+impl FeedFilterArgs {
+    /// 构建 v2 查询参数，解析作者 linkid 和技能名称
+    pub async fn to_query(
+        &self,
+        client: &ApiClient,
+        config: &Config,
+        page: Option<i64>,
+        per_page: Option<i64>,
+    ) -> Result<FeedQueryV2> {
+        let mut authors = Vec::with_capacity(self.authors.len());
+        for author in &self.authors {
+            authors.push(client.resolve_user_id(author).await?);
+        }
+
+        let skills = if self.skills.is_empty() {
+            Vec::new()
+        } else {
+            SkillCatalog::load(client, config, false)
+                .await?
+                .resolve_ids(&self.skills)?
+        };
+
+        Ok(self.build_query(page, per_page, authors, skills))
+    }
+
+    /// 使用已解析的作者 ID 和技能 ID 构建查询参数
+    fn build_query(
+        &self,
+        page: Option<i64>,
+        per_page: Option<i64>,
+        authors: Vec<Uuid>,
+        skills: Vec<Uuid>,
+    ) -> FeedQueryV2 {
+        FeedQueryV2 {
+            page,
+            per_page,
+            cursor: None,
+            item_types: FeedItemTypeArg::list_into_protocol(&self.item_types),
+            item_subtypes: non_empty(self.item_subtypes.clone()),
+            exclude_types: non_empty(
+                self.exclude_types
+                    .iter()
+                    .filter_map(|value| value.into_protocol())
+                    .collect(),
+            ),
+            author_types: non_empty(
+                self.author_types
+                    .iter()
+                    .map(|value| UserType::from(*value))
+                    .collect(),
+            ),
+            author_ids: non_empty(authors),
+            following_only: if self.following { Some(true) } else { None },
+            q: self.q.clone(),
+            tags: non_empty(self.tags.clone()),
+            skills: non_empty(skills),
+            time_range: self.time_range.map(|value| value.as_str().to_string()),
+            location: self.location.clone(),
+            radius_km: self.radius_km,
+            preferred_subtypes: non_empty(self.preferred_subtypes.clone()),
+            exclude_viewed: if self.exclude_viewed { Some(true) } else { None },
+            include_system: Some(!self.no_system),
+        }
+    }
+}
+
+fn non_empty<T>(values: Vec<T>) -> Option<Vec<T>> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values)
+    }
+}
+
+#[derive(Subcommand)]
+pub enum FeedCommands {
+    /// 列出当前 agent 的动态流 (v2 API)
+    List {
+        #[arg(long, default_value = "1")]
+        page: i64,
+
+        #[arg(long = "per-page", default_value = "20")]
+        per_page: i64,
+
+        /// 展示每条动态的完整内容（预算、技能、服务报价等）
+        #[arg(long)]
+        detail: bool,
+
+        #[command(flatten)]
+        filters: FeedFilterArgs,
+    },
+
+    /// 查看单条动态的完整内容
+    Show {
+        /// 动态 ID
+        id: String,
+
+        /// 最多向后查找的页数（每页 50 条）
+        #[arg(long = "max-pages", default_value = "10")]
+        max_pages: i64,
+    },
+
+    /// 生成一段时间内的动态摘要（Markdown 或 HTML）
+    Digest {
+        /// 统计窗口（例如 24h、7d）
+        #[arg(long, default_value = "24h", value_parser = parse_duration)]
+        since: std::time::Duration,
+
+        /// 输出文件路径，不指定则输出到标准输出；扩展名为 .html 时生成 HTML
+        #[arg(short, long)]
+        out: Option<std::path::PathBuf>,
+
+        /// 生成 HTML 而不是 Markdown
+        #[arg(long)]
+        html: bool,
+
+        /// 每个栏目最多列出的条目数
+        #[arg(long, default_value = "10")]
+        top: usize,
+
+        /// 最多拉取的页数（每页 50 条）
+        #[arg(long = "max-pages", default_value = "20")]
+        max_pages: i64,
+
+        #[command(flatten)]
+        filters: FeedFilterArgs,
+    },
+
+    /// 持续跟随动态流，只输出新出现的内容（重启后从上次位置继续）
+    ///
+    /// 每次轮询都拉取最新一页，按发布时间和 ID 找出上次之后的内容。服务端游标只能
+    /// 向更旧的内容翻页，无法用来等待新内容，因此不使用游标；两次轮询之间新增的内容
+    /// 超过 --per-page 条时，较早的部分会被跳过并给出警告。
+    Tail {
+        /// 轮询间隔（例如 30s、5m）
+        #[arg(long, default_value = "30s", value_parser = parse_interval)]
+        interval: std::time::Duration,
+
+        #[arg(long = "per-page", default_value = "20")]
+        per_page: i64,
+
+        /// 进度状态名，不同过滤条件可使用不同的状态名分别跟随
+        #[arg(long, default_value = "default")]
+        state: String,
+
+        /// 丢弃已保存的进度，从最新内容重新开始
+        #[arg(long)]
+        reset: bool,
+
+        #[command(flatten)]
+        filters: FeedFilterArgs,
+    },
+}
+
+pub async fn execute(
+    command: FeedCommands,
+    config: &Config,
+    format: crate::OutputFormat,
+) -> Result<()> {
+    ensure_authenticated(config)?;
+    let client = ApiClient::new(config)?;
+
+    match command {
+        FeedCommands::List {
+            page,
+            per_page,
+            detail,
+            filters,
+        } => {
+            let following = filters.following;
+            let query = filters
+                .to_query(&client, config, Some(page), Some(per_page))
+                .await?;
+
+            match client.get_feed(query).await {
+                Ok(data) => {
+                    if data.items.is_empty() {
+                        println!("{}", "No feed items found.".yellow());
+                        
+                        // 提供帮助信息
+                        if following {
+                            println!("{}", "You used --following flag but don't have any connections yet.".dimmed());
+                            println!("{}", "Try: agentlink feed list (without --following)".dimmed());
+                        } else {
+                            println!("{}", "This could mean:".dimmed());
+                            println!("  • You're a new agent with no feed history");
+                            println!("  • No content is available for your current filters");
+                            println!("  • Try: agentlink feed list --type all (or drop some filters)");
+                        }
+                        
+                        return Ok(());
+                    }
+
+                    match format {
+                        crate::OutputFormat::Json => {
+                            println!("{}", serde_json::to_string_pretty(&data)?);
+                        }
+                        crate::OutputFormat::Yaml => {
+                            println!("{}", serde_yaml::to_string(&data)?);
+                        }
+                        _ => print_feed_v2(data, page, detail),
+                    }
+                    Ok(())
+                }
+                Err(error) => {
+                    print_error(&format!("Failed to list feed: {}", error));
+                    Ok(())
+                }
+            }
+        }
+        FeedCommands::Show { id, max_pages } => {
+            let item = match find_feed_item(&client, &id, max_pages).await {
+                Ok(Some(item)) => item,
+                Ok(None) => {
+                    print_error(&format!(
+                        "Feed item not found in the latest {} page(s): {}",
+                        max_pages, id
+                    ));
+                    return Ok(());
+                }
+                Err(error) => {
+                    print_error(&format!("Failed to fetch feed: {}", error));
+                    return Ok(());
+                }
+            };
+
+            match format {
+                crate::OutputFormat::Json => {
+                    println!("{}", serde_json::to_string_pretty(&item)?);
+                }
+                crate::OutputFormat::Yaml => {
+                    println!("{}", serde_yaml::to_string(&item)?);
+                }
+                _ => {
+                    println!("\n{}\n", "Feed Item".bold().underline());
+                    detail::print_feed_item_detail("•", &item);
+                }
+            }
+            Ok(())
+        }
+        FeedCommands::Digest {
+            since,
+            out,
+            html,
+            top,
+            max_pages,
+            filters,
+        } => {
+            let since = chrono::Duration::from_std(since)
+                .map_err(|_| anyhow::anyhow!("`--since` is too large"))?;
+            digest::run(
+                &client,
+                config,
+                digest::DigestOptions {
+                    filters,
+                    since,
+                    out,
+                    html,
+                    top,
+                    max_pages,
+                },
+            )
+            .await
+        }
+        FeedCommands::Tail {
+            interval,
+            per_page,
+            state,
+            reset,
+            filters,
+        } => {
+            tail::run(
+                &client,
+                config,
+                tail::TailOptions {
+                    filters,
+                    per_page,
+                    interval,
+                    state_name: state,
+                    reset,
+                },
+                format,
+            )
+            .await
+        }
+    }
+}
+
+fn ensure_authenticated(config: &Config) -> Result<()> {
+    if config.has_api_key() {
+        Ok(())
+    } else {
+        anyhow::bail!(
+            "No agent API key configured. Run `agentlink api-key set <sk_...>` or pass `--api-key`."
+        )
+    }
+}
+
+/// 动态流没有单条查询接口，按页向后查找指定 ID 的动态
+async fn find_feed_item(
+    client: &ApiClient,
+    id: &str,
+    max_pages: i64,
+) -> Result<Option<FeedItemV2>> {
+    scan_feed_pages(
+        max_pages,
+        |query| async move { client.get_feed(query).await.map(|data| data.items) },
+        |item: &FeedItemV2| item.id.to_string() == id,
+    )
+    .await
+}
+
+/// 从第一页开始逐页拉取不带过滤条件的动态流，返回第一条满足条件的内容；
+/// 某一页不满或达到页数上限时停止
+async fn scan_feed_pages<T, F, Fut>(
+    max_pages: i64,
+    mut fetch: F,
+    is_target: impl Fn(&T) -> bool,
+) -> Result<Option<T>>
+where
+    F: FnMut(FeedQueryV2) -> Fut,
+    Fut: std::future::Future<Output = Result<Vec<T>>>,
+{
+    for page in 1..=max_pages.max(1) {
+        let query = FeedFilterArgs::default().build_query(
+            Some(page),
+            Some(SHOW_SCAN_PAGE_SIZE),
+            Vec::new(),
+            Vec::new(),
+        );
+
+        let items = fetch(query).await?;
+        let exhausted = (items.len() as i64) < SHOW_SCAN_PAGE_SIZE;
+        if let Some(item) = items.into_iter().find(&is_target) {
+            return Ok(Some(item));
+        }
+        if exhausted {
+            break;
+        }
+    }
+
+    Ok(None)
+}
+
+fn print_feed_v2(data: FeedDataV2, current_page: i64, detail: bool) {
+    println!(
+        "\n{} (Page {}/{}):",
+        "Feed".bold().underline(),
+        current_page,
+        data.total_pages
+    );
+    println!("Total items: {}\n", data.total);
+    
+    for (index, item) in data.items.iter().enumerate() {
+        let prefix = format!("{}.", index + 1);
+        if detail {
+            detail::print_feed_item_detail(&prefix, item);
+        } else {
+            print_feed_item(&prefix, item);
+        }
+    }
+}
+
+/// 打印单条动态，`prefix` 为行首标记（序号或时间）
+fn print_feed_item(prefix: &str, item: &FeedItemV2) {
+    let author_name = if item.author.display_name.is_empty() {
+        &item.author.linkid
+    } else {
+        &item.author.display_name
+    };
+
+    match &item.content_data {
+        ContentData::Post(_post) => {
+            let content = item.content.as_deref().unwrap_or("No content");
+            println!(
+                "{} [{}] {} - {}",
+                prefix,
+                "POST".cyan(),
+                author_name.bold(),
+                truncate(content, 80)
+            );
+        }
+        ContentData::Task(_task) => {
+            let title = item.title.as_deref().unwrap_or("Untitled Task");
+            println!(
+                "{} [{}] {} - {}",
+                prefix,
+                "TASK".green(),
+                author_name.bold(),
+                title
+            );
+        }
+        ContentData::UserCard(_) => {
+            println!(
+                "{} [{}] {} - User profile",
+                prefix,
+                "USER".blue(),
+                author_name.bold(),
+            );
+        }
+        ContentData::AgentOffer(_) => {
+            println!(
+                "{} [{}] {} - Agent offer",
+                prefix,
+                "AGENT".magenta(),
+                author_name.bold(),
+            );
+        }
+        ContentData::System(system) => {
+            println!("{} [{}] {}", prefix, "SYSTEM".yellow(), system.title);
+        }
+        _ => {
+            // 新增的内容类型：使用类型名作为标签，尽量展示标题或正文
+            let label = format!("{:?}", item.item_type).to_uppercase();
+            let summary = item
+                .title
+                .as_deref()
+                .or(item.content.as_deref())
+                .map(|text| truncate(text, 80))
+                .unwrap_or_else(|| "(use --detail to see the payload)".to_string());
+            println!(
+                "{} [{}] {} - {}",
+                prefix,
+                label.dimmed(),
+                author_name.bold(),
+                summary
+            );
+        }
+    }
+
+    // 显示互动统计
+    let engagement = format!(
+        "   👍 {}  💬 {}  🔄 {}",
+        item.engagement.like_count,
+        item.engagement.comment_count,
+        item.engagement.share_count
+    );
+    println!("{}\n", engagement.dimmed());
+}
+
+fn truncate(s: &str, max_len: usize) -> String {
+    // 按字符截取而非字节，避免 UTF-8 边界错误
+    let chars: Vec<char> = s.chars().collect();
+    if chars.len() <= max_len {
+        s.to_string()
+    } else {
+        let truncated: String = chars.into_iter().take(max_len).collect();
+        format!("{}...", truncated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_into_protocol_maps_types_and_treats_all_as_unfiltered() {
+        assert!(FeedItemTypeArg::list_into_protocol(&[]).is_none());
+        assert!(FeedItemTypeArg::list_into_protocol(&[
+            FeedItemTypeArg::Task,
+            FeedItemTypeArg::All
+        ])
+        .is_none());
+
+        let types =
+            FeedItemTypeArg::list_into_protocol(&[FeedItemTypeArg::User, FeedItemTypeArg::Post])
+                .unwrap();
+        assert!(matches!(
+            types.as_slice(),
+            [FeedItemTypeV2::UserCard, FeedItemTypeV2::Post]
+        ));
+    }
+
+    #[test]
+    fn test_build_query_leaves_unset_filters_empty() {
+        let query =
+            FeedFilterArgs::default().build_query(Some(2), Some(10), Vec::new(), Vec::new());
+
+        assert_eq!(query.page, Some(2));
+        assert_eq!(query.per_page, Some(10));
+        assert!(query.item_types.is_none());
+        assert!(query.exclude_types.is_none());
+        assert!(query.author_ids.is_none());
+        assert!(query.tags.is_none());
+        assert!(query.skills.is_none());
+        assert!(query.following_only.is_none());
+        assert!(query.exclude_viewed.is_none());
+        assert_eq!(query.include_system, Some(true));
+    }
+
+    #[test]
+    fn test_build_query_maps_every_filter() {
+        let author = Uuid::new_v4();
+        let skill = Uuid::new_v4();
+        let filters = FeedFilterArgs {
+            following: true,
+            item_types: vec![FeedItemTypeArg::Task],
+            exclude_types: vec![FeedItemTypeArg::All, FeedItemTypeArg::System],
+            author_types: vec![AuthorTypeArg::Agent],
+            authors: vec!["@alice".to_string()],
+            tags: vec!["rust".to_string()],
+            time_range: Some(TimeRangeArg::Week),
+            exclude_viewed: true,
+            no_system: true,
+            q: Some("review".to_string()),
+            ..FeedFilterArgs::default()
+        };
+
+        let query = filters.build_query(None, None, vec![author], vec![skill]);
+
+        assert!(matches!(
+            query.item_types.as_deref(),
+            Some([FeedItemTypeV2::Task])
+        ));
+        assert!(matches!(
+            query.exclude_types.as_deref(),
+            Some([FeedItemTypeV2::System])
+        ));
+        assert!(matches!(
+            query.author_types.as_deref(),
+            Some([UserType::Agent])
+        ));
+        assert_eq!(query.author_ids, Some(vec![author]));
+        assert_eq!(query.skills, Some(vec![skill]));
+        assert_eq!(query.tags, Some(vec!["rust".to_string()]));
+        assert_eq!(query.time_range.as_deref(), Some("7d"));
+        assert_eq!(query.following_only, Some(true));
+        assert_eq!(query.exclude_viewed, Some(true));
+        assert_eq!(query.include_system, Some(false));
+        assert_eq!(query.q.as_deref(), Some("review"));
+    }
+
+    /// 模拟分页：每页 `SHOW_SCAN_PAGE_SIZE` 条，共 `pages` 页，最后一页不满
+    fn fake_page(page: i64, pages: i64) -> Vec<String> {
+        let size = match page.cmp(&pages) {
+            std::cmp::Ordering::Less => SHOW_SCAN_PAGE_SIZE,
+            std::cmp::Ordering::Equal => SHOW_SCAN_PAGE_SIZE - 1,
+            std::cmp::Ordering::Greater => 0,
+        };
+        (0..size)
+            .map(|index| format!("{}-{}", page, index))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_scan_feed_pages_walks_pages_until_the_item_is_found() {
+        let mut requested = Vec::new();
+        let found = scan_feed_pages(
+            10,
+            |query| {
+                requested.push((query.page, query.per_page, query.include_system));
+                let items = fake_page(query.page.unwrap(), 5);
+                async move { Ok(items) }
+            },
+            |item: &String| item == "2-7",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(found.as_deref(), Some("2-7"));
+        assert_eq!(
+            requested,
+            vec![
+                (Some(1), Some(SHOW_SCAN_PAGE_SIZE), Some(true)),
+                (Some(2), Some(SHOW_SCAN_PAGE_SIZE), Some(true)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scan_feed_pages_stops_at_the_last_page_or_page_limit() {
+        let mut pages = Vec::new();
+        let found = scan_feed_pages(
+            10,
+            |query| {
+                pages.push(query.page.unwrap());
+                let items = fake_page(query.page.unwrap(), 3);
+                async move { Ok(items) }
+            },
+            |item: &String| item == "missing",
+        )
+        .await
+        .unwrap();
+        assert_eq!(found, None);
+        assert_eq!(pages, vec![1, 2, 3]);
+
+        pages.clear();
+        let found = scan_feed_pages(
+            2,
+            |query| {
+                pages.push(query.page.unwrap());
+                let items = fake_page(query.page.unwrap(), 5);
+                async move { Ok(items) }
+            },
+            |item: &String| item == "missing",
+        )
+        .await
+        .unwrap();
+        assert_eq!(found, None);
+        assert_eq!(pages, vec![1, 2]);
+    }
+}
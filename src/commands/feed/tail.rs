@@ -0,0 +1,243 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use super::{print_feed_item, FeedFilterArgs};
+use crate::api::ApiClient;
+use crate::config::Config;
+use crate::utils::output::{print_error, print_warning};
+use crate::utils::state;
+use crate::utils::time::sleep_or_interrupt;
+
+/// 去重时最多记住的动态 ID 数量
+const MAX_SEEN_ITEMS: usize = 2000;
+
+/// 跟随参数
+pub struct TailOptions {
+    pub filters: FeedFilterArgs,
+    pub per_page: i64,
+    pub interval: Duration,
+    pub state_name: String,
+    pub reset: bool,
+}
+
+/// 持久化的跟随进度：已输出的最新动态时间与最近已输出的动态 ID
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct TailState {
+    last_seen_at: Option<DateTime<Utc>>,
+    seen: VecDeque<String>,
+}
+
+impl TailState {
+    /// 从最新一页（按时间倒序）中取出上次之后出现的动态，按从旧到新返回并更新进度
+    ///
+    /// 遇到已输出过的 ID 或早于已输出最新时间的动态即停止
+    fn take_new<'a, T>(
+        &mut self,
+        head: &'a [T],
+        key: impl Fn(&T) -> (String, DateTime<Utc>),
+    ) -> Vec<&'a T> {
+        let mut fresh = Vec::new();
+        for item in head {
+            let (id, created_at) = key(item);
+            if self.seen.contains(&id) || self.last_seen_at.is_some_and(|last| created_at < last) {
+                break;
+            }
+            fresh.push((item, id, created_at));
+        }
+
+        fresh.reverse();
+        fresh
+            .into_iter()
+            .map(|(item, id, created_at)| {
+                self.record(id);
+                self.last_seen_at = self.last_seen_at.max(Some(created_at));
+                item
+            })
+            .collect()
+    }
+
+    /// 记录动态 ID，已出现过时返回 false
+    fn record(&mut self, id: String) -> bool {
+        if self.seen.contains(&id) {
+            return false;
+        }
+
+        self.seen.push_back(id);
+        while self.seen.len() > MAX_SEEN_ITEMS {
+            self.seen.pop_front();
+        }
+        true
+    }
+}
+
+pub async fn run(
+    client: &ApiClient,
+    config: &Config,
+    options: TailOptions,
+    format: crate::OutputFormat,
+) -> Result<()> {
+    let file_name = state_file_name(&options.state_name)?;
+    let mut tail_state: TailState = if options.reset {
+        TailState::default()
+    } else {
+        state::load(config, &file_name)?
+    };
+
+    let streaming_json = matches!(format, crate::OutputFormat::Json);
+    if !streaming_json {
+        println!(
+            "{}",
+            format!(
+                "Following feed (state `{}`), polling every {}s. Press Ctrl+C to exit.",
+                options.state_name,
+                options.interval.as_secs()
+            )
+            .cyan()
+        );
+    }
+
+    // 技能名称只需解析一次
+    let base_query = options
+        .filters
+        .to_query(client, config, None, Some(options.per_page))
+        .await?;
+
+    loop {
+        // 每次都拉取最新一页，不使用游标（游标指向更旧的内容）
+        match client.get_feed(base_query.clone()).await {
+            Ok(data) => {
+                let resumed = tail_state.last_seen_at.is_some();
+                let fresh =
+                    tail_state.take_new(&data.items, |item| (item.id.to_string(), item.created_at));
+                if resumed && !fresh.is_empty() && fresh.len() == data.items.len() {
+                    report_warning(
+                        streaming_json,
+                        &format!(
+                            "More than {} new item(s) since the last poll; older ones were skipped. Use a shorter --interval or a larger --per-page.",
+                            data.items.len()
+                        ),
+                    );
+                }
+
+                for item in fresh {
+                    if streaming_json {
+                        println!("{}", serde_json::to_string(item)?);
+                    } else {
+                        let received = chrono::Local::now().format("%H:%M:%S").to_string();
+                        print_feed_item(&received.dimmed().to_string(), item);
+                    }
+                }
+
+                state::save(config, &file_name, &tail_state)?;
+            }
+            Err(error) => {
+                report_error(streaming_json, &format!("Failed to fetch feed: {}", error));
+            }
+        }
+
+        if !sleep_or_interrupt(options.interval).await {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// 打印警告；NDJSON 模式下写到标准错误，保证标准输出每行都是一条动态
+fn report_warning(streaming_json: bool, message: &str) {
+    if streaming_json {
+        eprintln!("{}", format!("! {}", message).yellow());
+    } else {
+        print_warning(message);
+    }
+}
+
+/// 打印错误；NDJSON 模式下写到标准错误
+fn report_error(streaming_json: bool, message: &str) {
+    if streaming_json {
+        eprintln!("{}", format!("✗ {}", message).red());
+    } else {
+        print_error(message);
+    }
+}
+
+/// 校验状态名并返回对应的状态文件名
+fn state_file_name(name: &str) -> Result<String> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_');
+    if !valid {
+        anyhow::bail!(
+            "Invalid state name `{}`: use letters, digits, `-` or `_`.",
+            name
+        );
+    }
+
+    Ok(format!("feed_tail_{}.json", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_record_skips_seen_items() {
+        let mut tail_state = TailState::default();
+        assert!(tail_state.record("a".to_string()));
+        assert!(tail_state.record("b".to_string()));
+        assert!(!tail_state.record("a".to_string()));
+    }
+
+    #[test]
+    fn test_record_keeps_seen_set_bounded() {
+        let mut tail_state = TailState::default();
+        for index in 0..MAX_SEEN_ITEMS + 10 {
+            tail_state.record(index.to_string());
+        }
+
+        assert_eq!(tail_state.seen.len(), MAX_SEEN_ITEMS);
+        assert!(tail_state.record("0".to_string()));
+    }
+
+    fn at(minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 1, 9, minute, 0).unwrap()
+    }
+
+    fn ids(items: Vec<&(&str, DateTime<Utc>)>) -> Vec<String> {
+        items.into_iter().map(|(id, _)| id.to_string()).collect()
+    }
+
+    #[test]
+    fn test_take_new_emits_only_items_above_the_last_seen_head() {
+        let key = |item: &(&str, DateTime<Utc>)| (item.0.to_string(), item.1);
+        let mut tail_state = TailState::default();
+
+        let first_poll = [("b", at(2)), ("a", at(1))];
+        assert_eq!(ids(tail_state.take_new(&first_poll, key)), vec!["a", "b"]);
+
+        // 第二次轮询：头部新插入 c，其余仍是上次看到的内容
+        let second_poll = [("c", at(3)), ("b", at(2)), ("a", at(1))];
+        assert_eq!(ids(tail_state.take_new(&second_poll, key)), vec!["c"]);
+        assert_eq!(tail_state.last_seen_at, Some(at(3)));
+
+        // 已输出的 c 被删除后，头部不会把更旧的内容当成新内容
+        let third_poll = [("b", at(2)), ("a", at(1))];
+        assert!(tail_state.take_new(&third_poll, key).is_empty());
+    }
+
+    #[test]
+    fn test_state_file_name_rejects_path_characters() {
+        assert_eq!(
+            state_file_name("agent-1").unwrap(),
+            "feed_tail_agent-1.json"
+        );
+        assert!(state_file_name("../x").is_err());
+        assert!(state_file_name("").is_err());
+    }
+}
@@ -0,0 +1,386 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use clap::Args;
+use colored::Colorize;
+use console::Term;
+use serde::Serialize;
+use std::time::Duration;
+
+use crate::api::ApiClient;
+use crate::commands::messages::history::conversation_label;
+use crate::commands::notifications::filter::{self, kind_name};
+use crate::config::Config;
+use crate::models::{ConversationResponse, TaskResponse};
+use crate::utils::time::{parse_duration, parse_interval, sleep_or_interrupt};
+use agentlink_protocol::message::NotificationResponse;
+
+/// 每次拉取新任务的条数
+const TASK_PAGE_SIZE: i64 = 50;
+
+/// 翻页拉取未读通知时的每页条数
+const NOTIFICATION_PAGE_SIZE: i64 = 50;
+
+#[derive(Args, Debug)]
+pub struct InboxArgs {
+    /// 以 JSON 输出，等同于 `--format json`；与 --watch 一起使用时每次刷新输出一行
+    #[arg(long)]
+    pub json: bool,
+
+    /// 持续刷新收件箱
+    #[arg(short, long)]
+    pub watch: bool,
+
+    /// 刷新间隔（例如 30s、1m）
+    #[arg(long, default_value = "30s", value_parser = parse_interval)]
+    pub interval: Duration,
+
+    /// 新任务搜索词，覆盖配置项 inbox_task_query
+    #[arg(long)]
+    pub tasks: Option<String>,
+
+    /// 只显示该时间窗口内发布的任务（例如 24h、3d）
+    #[arg(long, default_value = "24h", value_parser = parse_duration)]
+    pub task_window: Duration,
+
+    /// 最多显示的条目数
+    #[arg(long, default_value = "50")]
+    pub limit: usize,
+}
+
+/// 条目来源
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ItemKind {
+    Conversation,
+    Notification,
+    Task,
+}
+
+/// 优先级，按声明顺序排序
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Priority {
+    High,
+    Normal,
+    Low,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InboxItem {
+    pub kind: ItemKind,
+    pub id: String,
+    pub priority: Priority,
+    pub title: String,
+    pub detail: String,
+    pub time: DateTime<Utc>,
+    /// 可直接执行的后续命令，第一条为默认操作
+    pub actions: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Inbox {
+    pub generated_at: DateTime<Utc>,
+    pub items: Vec<InboxItem>,
+    /// 拉取失败的来源，其余来源的结果仍然有效
+    pub errors: Vec<String>,
+}
+
+pub async fn execute(args: InboxArgs, config: &Config, format: crate::OutputFormat) -> Result<()> {
+    ensure_authenticated(config)?;
+    let client = ApiClient::new(config)?;
+    let format = if args.json {
+        crate::OutputFormat::Json
+    } else {
+        format
+    };
+    let task_query = args
+        .tasks
+        .clone()
+        .or_else(|| config.inbox.task_query.clone())
+        .filter(|query| !query.trim().is_empty());
+    let term = Term::stdout();
+
+    loop {
+        let inbox = collect(&client, task_query.as_deref(), &args).await;
+
+        match format {
+            crate::OutputFormat::Json if args.watch => {
+                println!("{}", serde_json::to_string(&inbox)?);
+            }
+            crate::OutputFormat::Json => {
+                println!("{}", serde_json::to_string_pretty(&inbox)?);
+            }
+            crate::OutputFormat::Yaml => {
+                println!("{}", serde_yaml::to_string(&inbox)?);
+            }
+            _ => {
+                if args.watch {
+                    term.clear_screen()?;
+                }
+                print_inbox(&inbox, task_query.is_some());
+                if args.watch {
+                    println!(
+                        "\n{}",
+                        format!(
+                            "Updated {} · refreshing every {}s · Ctrl+C to exit",
+                            chrono::Local::now().format("%H:%M:%S"),
+                            args.interval.as_secs()
+                        )
+                        .dimmed()
+                    );
+                }
+            }
+        }
+
+        if !args.watch || !sleep_or_interrupt(args.interval).await {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// 并发拉取三个来源并合并排序；单个来源失败不影响其他来源
+async fn collect(client: &ApiClient, task_query: Option<&str>, args: &InboxArgs) -> Inbox {
+    let now = Utc::now();
+    let (conversations, notifications, tasks) = tokio::join!(
+        client.list_all_conversations(),
+        filter::fetch_all(client, true, NOTIFICATION_PAGE_SIZE),
+        fetch_new_tasks(client, task_query),
+    );
+
+    let mut items = Vec::new();
+    let mut errors = Vec::new();
+
+    match conversations {
+        Ok(conversations) => items.extend(
+            conversations
+                .iter()
+                .filter(|conversation| conversation.unread_count > 0)
+                .map(conversation_item),
+        ),
+        Err(error) => errors.push(format!("conversations: {}", error)),
+    }
+    match notifications {
+        Ok(notifications) => items.extend(
+            notifications
+                .iter()
+                .filter(|notification| !notification.is_read)
+                .map(notification_item),
+        ),
+        Err(error) => errors.push(format!("notifications: {}", error)),
+    }
+    match tasks {
+        Ok(tasks) => {
+            let window = chrono::Duration::from_std(args.task_window)
+                .unwrap_or_else(|_| chrono::Duration::days(1));
+            items.extend(
+                tasks
+                    .iter()
+                    .filter(|task| task.created_at >= now - window)
+                    .map(task_item),
+            );
+        }
+        Err(error) => errors.push(format!("tasks: {}", error)),
+    }
+
+    Inbox {
+        generated_at: now,
+        items: prioritize(items, args.limit),
+        errors,
+    }
+}
+
+async fn fetch_new_tasks(client: &ApiClient, query: Option<&str>) -> Result<Vec<TaskResponse>> {
+    let Some(query) = query else {
+        return Ok(Vec::new());
+    };
+
+    let response = client
+        .list_tasks(agentlink_protocol::task::TaskSearchQuery {
+            q: Some(query.to_string()),
+            task_type: None,
+            status: None,
+            budget_min: None,
+            budget_max: None,
+            skill_ids: None,
+            page: Some(1),
+            per_page: Some(TASK_PAGE_SIZE),
+        })
+        .await?;
+    Ok(response.data)
+}
+
+/// 按优先级排序，同一优先级内最新的在前
+fn prioritize(mut items: Vec<InboxItem>, limit: usize) -> Vec<InboxItem> {
+    items.sort_by(|a, b| a.priority.cmp(&b.priority).then(b.time.cmp(&a.time)));
+    items.truncate(limit);
+    items
+}
+
+fn conversation_item(conversation: &ConversationResponse) -> InboxItem {
+    let id = conversation.id.to_string();
+    InboxItem {
+        kind: ItemKind::Conversation,
+        priority: Priority::High,
+        title: conversation_label(conversation),
+        detail: format!("{} unread message(s)", conversation.unread_count),
+        time: conversation
+            .last_message_at
+            .unwrap_or(conversation.created_at),
+        actions: vec![
+            format!("agentlink messages chat {}", id),
+            format!("agentlink messages show {}", id),
+        ],
+        id,
+    }
+}
+
+fn notification_item(notification: &NotificationResponse) -> InboxItem {
+    let id = notification.id.to_string();
+    let detail = notification
+        .content
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| kind_name(notification));
+
+    InboxItem {
+        kind: ItemKind::Notification,
+        priority: Priority::Normal,
+        title: notification.title.clone(),
+        detail,
+        time: notification.created_at,
+        actions: vec![format!("agentlink notifications mark-read {}", id)],
+        id,
+    }
+}
+
+fn task_item(task: &TaskResponse) -> InboxItem {
+    let id = task.id.to_string();
+    InboxItem {
+        kind: ItemKind::Task,
+        priority: Priority::Low,
+        title: task.title.clone(),
+        detail: task
+            .description
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string(),
+        time: task.created_at,
+        actions: vec![
+            format!("agentlink tasks show {}", id),
+            format!("agentlink tasks apply {}", id),
+        ],
+        id,
+    }
+}
+
+fn print_inbox(inbox: &Inbox, tasks_configured: bool) {
+    println!(
+        "\n{} ({}):\n",
+        "Inbox".bold().underline(),
+        inbox.items.len()
+    );
+
+    if inbox.items.is_empty() {
+        println!("{}", "Nothing needs your attention.".green());
+    }
+
+    for (position, item) in inbox.items.iter().enumerate() {
+        let label = match item.kind {
+            ItemKind::Conversation => "[message]".cyan(),
+            ItemKind::Notification => "[notification]".yellow(),
+            ItemKind::Task => "[task]".magenta(),
+        };
+        println!(
+            "{}. {} {} {}",
+            position + 1,
+            label,
+            item.title.bold(),
+            item.time.format("%Y-%m-%d %H:%M").to_string().dimmed()
+        );
+        if !item.detail.is_empty() {
+            println!("   {}", item.detail);
+        }
+        if let Some(action) = item.actions.first() {
+            println!("   {}\n", format!("→ {}", action).dimmed());
+        }
+    }
+
+    for error in &inbox.errors {
+        println!("{}", format!("Failed to load {}", error).red());
+    }
+    if !tasks_configured {
+        println!(
+            "{}",
+            "Tip: set `agentlink config set inbox_task_query <query>` to include new tasks."
+                .dimmed()
+        );
+    }
+}
+
+fn ensure_authenticated(config: &Config) -> Result<()> {
+    if config.has_api_key() {
+        Ok(())
+    } else {
+        anyhow::bail!(
+            "No agent API key configured. Run `agentlink api-key set <sk_...>` or pass `--api-key`."
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn item(kind: ItemKind, priority: Priority, id: &str, hour: u32) -> InboxItem {
+        InboxItem {
+            kind,
+            id: id.to_string(),
+            priority,
+            title: String::new(),
+            detail: String::new(),
+            time: Utc.with_ymd_and_hms(2026, 3, 1, hour, 0, 0).unwrap(),
+            actions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_prioritize_orders_by_priority_then_recency() {
+        let items = vec![
+            item(ItemKind::Task, Priority::Low, "t1", 12),
+            item(ItemKind::Notification, Priority::Normal, "n1", 8),
+            item(ItemKind::Conversation, Priority::High, "c1", 9),
+            item(ItemKind::Notification, Priority::Normal, "n2", 10),
+        ];
+
+        let ids: Vec<String> = prioritize(items, 10)
+            .into_iter()
+            .map(|item| item.id)
+            .collect();
+        assert_eq!(ids, vec!["c1", "n2", "n1", "t1"]);
+    }
+
+    #[test]
+    fn test_prioritize_applies_limit_after_sorting() {
+        let items = vec![
+            item(ItemKind::Task, Priority::Low, "t1", 12),
+            item(ItemKind::Conversation, Priority::High, "c1", 9),
+        ];
+
+        let limited = prioritize(items, 1);
+        assert_eq!(limited.len(), 1);
+        assert_eq!(limited[0].id, "c1");
+    }
+
+    #[test]
+    fn test_item_serializes_lowercase_enums() {
+        let value = serde_json::to_value(item(ItemKind::Task, Priority::Low, "t1", 1)).unwrap();
+        assert_eq!(value["kind"], "task");
+        assert_eq!(value["priority"], "low");
+    }
+}
@@ -0,0 +1,287 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use clap::Subcommand;
+use colored::Colorize;
+use std::collections::BTreeMap;
+
+use crate::api::ApiClient;
+use crate::commands::messages::history::{conversation_label, message_query, HISTORY_PAGE_SIZE};
+use crate::commands::posts::stats::fetch_all_posts;
+use crate::config::Config;
+use crate::models::{ConversationResponse, MessageResponse};
+use crate::utils::output::{print_error, print_success, print_table, print_warning};
+
+pub mod store;
+
+use store::{DocKind, Document, SearchIndex};
+
+/// 同步状态中记录最近一次同步时间的键
+const LAST_SYNC_KEY: &str = "last_sync";
+
+#[derive(Subcommand)]
+pub enum IndexCommands {
+    /// 增量拉取会话消息、自己的动态和任务到本地索引
+    Sync {
+        /// 忽略已记录的进度，重新拉取全部消息
+        #[arg(long)]
+        full: bool,
+    },
+
+    /// 查看本地索引的条目数和最近同步时间
+    Status,
+}
+
+pub async fn execute(
+    command: IndexCommands,
+    config: &Config,
+    format: crate::OutputFormat,
+) -> Result<()> {
+    let mut index = SearchIndex::open_default(config)?;
+
+    match command {
+        IndexCommands::Sync { full } => {
+            ensure_authenticated(config)?;
+            let client = ApiClient::new(config)?;
+            sync(&client, &mut index, full).await
+        }
+        IndexCommands::Status => {
+            let counts = index.counts()?;
+            let last_sync = index.get_state(LAST_SYNC_KEY)?;
+
+            let summary = serde_json::json!({
+                "counts": counts.iter().cloned().collect::<BTreeMap<_, _>>(),
+                "last_sync": last_sync,
+            });
+
+            match format {
+                crate::OutputFormat::Json => {
+                    println!("{}", serde_json::to_string_pretty(&summary)?);
+                }
+                crate::OutputFormat::Yaml => {
+                    println!("{}", serde_yaml::to_string(&summary)?);
+                }
+                _ => {
+                    println!("\n{}\n", "Search Index".bold().underline());
+                    if counts.is_empty() {
+                        println!(
+                            "{}",
+                            "The index is empty. Run `agentlink index sync` first.".yellow()
+                        );
+                    } else {
+                        let rows = counts
+                            .iter()
+                            .map(|(kind, count)| vec![kind.clone(), count.to_string()])
+                            .collect();
+                        print_table(vec!["Type", "Entries"], rows);
+                    }
+                    println!(
+                        "\n{}: {}",
+                        "Last Sync".bold(),
+                        last_sync.as_deref().unwrap_or("never")
+                    );
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+async fn sync(client: &ApiClient, index: &mut SearchIndex, full: bool) -> Result<()> {
+    let mut failures = 0;
+
+    let messages = match sync_messages(client, index, full, &mut failures).await {
+        Ok(count) => count,
+        Err(error) => {
+            print_error(&format!("Failed to sync messages: {}", error));
+            failures += 1;
+            0
+        }
+    };
+
+    let posts = match sync_posts(client, index).await {
+        Ok(count) => count,
+        Err(error) => {
+            print_error(&format!("Failed to sync posts: {}", error));
+            failures += 1;
+            0
+        }
+    };
+
+    let tasks = match sync_tasks(client, index).await {
+        Ok(count) => count,
+        Err(error) => {
+            print_error(&format!("Failed to sync tasks: {}", error));
+            failures += 1;
+            0
+        }
+    };
+
+    let summary = format!(
+        "Indexed {} new message(s), {} post(s) and {} task(s).",
+        messages, posts, tasks
+    );
+    if failures == 0 {
+        index.set_state(LAST_SYNC_KEY, &store::format_time(Utc::now()))?;
+        print_success(&summary);
+    } else {
+        print_warning(&summary);
+    }
+    Ok(())
+}
+
+/// 同步所有会话的消息；单个会话失败时记录错误并继续同步其余会话
+async fn sync_messages(
+    client: &ApiClient,
+    index: &mut SearchIndex,
+    full: bool,
+    failures: &mut usize,
+) -> Result<usize> {
+    let conversations = client.list_all_conversations().await?;
+
+    let mut indexed = 0;
+    for conversation in &conversations {
+        match sync_conversation(client, index, conversation, full).await {
+            Ok(count) => indexed += count,
+            Err(error) => {
+                print_error(&format!(
+                    "Failed to sync conversation {}: {}",
+                    conversation.id, error
+                ));
+                *failures += 1;
+            }
+        }
+    }
+
+    Ok(indexed)
+}
+
+/// 向前翻页同步一个会话，直到遇到上次同步时已索引的消息
+async fn sync_conversation(
+    client: &ApiClient,
+    index: &mut SearchIndex,
+    conversation: &ConversationResponse,
+    full: bool,
+) -> Result<usize> {
+    let conversation_id = conversation.id.to_string();
+    let state_key = format!("messages:{}", conversation_id);
+    let watermark = if full {
+        None
+    } else {
+        index
+            .get_state(&state_key)?
+            .and_then(|value| value.parse::<DateTime<Utc>>().ok())
+    };
+
+    if let (Some(watermark), Some(last_message_at)) = (watermark, conversation.last_message_at) {
+        if last_message_at <= watermark {
+            return Ok(0);
+        }
+    }
+
+    let label = conversation_label(conversation);
+    let mut indexed = 0;
+    let mut newest = watermark;
+    let mut cursor: Option<uuid::Uuid> = None;
+
+    loop {
+        let batch = client
+            .get_messages(&conversation_id, message_query(cursor, HISTORY_PAGE_SIZE)?)
+            .await?;
+
+        let fresh: Vec<&MessageResponse> = batch
+            .iter()
+            .filter(|message| watermark.map_or(true, |watermark| message.created_at > watermark))
+            .collect();
+
+        index.transaction(|index| {
+            for message in &fresh {
+                index.upsert(&Document {
+                    kind: DocKind::Message,
+                    id: message.id.to_string(),
+                    conversation_id: Some(conversation_id.clone()),
+                    title: label.clone(),
+                    author: message.sender_name.clone(),
+                    body: message.content.clone(),
+                    created_at: message.created_at,
+                })?;
+            }
+            Ok(())
+        })?;
+        indexed += fresh.len();
+
+        if let Some(latest) = fresh.iter().map(|message| message.created_at).max() {
+            newest = Some(newest.map_or(latest, |newest| newest.max(latest)));
+        }
+
+        let oldest = batch.iter().min_by_key(|message| message.created_at);
+        let reached_watermark = fresh.len() < batch.len();
+        let next_cursor = oldest.map(|message| message.id);
+        if batch.len() < HISTORY_PAGE_SIZE
+            || reached_watermark
+            || next_cursor.is_none()
+            || next_cursor == cursor
+        {
+            break;
+        }
+        cursor = next_cursor;
+    }
+
+    if let Some(newest) = newest {
+        index.set_state(&state_key, &store::format_watermark(newest))?;
+    }
+
+    Ok(indexed)
+}
+
+async fn sync_posts(client: &ApiClient, index: &mut SearchIndex) -> Result<usize> {
+    let user = client.verify_agent_identity().await?;
+    let posts = fetch_all_posts(client, user.id).await?;
+
+    index.transaction(|index| {
+        for post in &posts {
+            index.upsert(&Document {
+                kind: DocKind::Post,
+                id: post.id.to_string(),
+                conversation_id: None,
+                title: String::new(),
+                author: post.author.linkid.clone(),
+                body: post.content.clone(),
+                created_at: post.created_at,
+            })?;
+        }
+        Ok(posts.len())
+    })
+}
+
+async fn sync_tasks(client: &ApiClient, index: &mut SearchIndex) -> Result<usize> {
+    let response = client.get_my_tasks().await?;
+
+    index.transaction(|index| {
+        for task in &response.tasks {
+            index.upsert(&Document {
+                kind: DocKind::Task,
+                id: task.id.to_string(),
+                conversation_id: None,
+                title: task.title.clone(),
+                author: task
+                    .creator
+                    .as_ref()
+                    .map(|creator| creator.linkid.clone())
+                    .unwrap_or_default(),
+                body: task.description.clone(),
+                created_at: task.created_at,
+            })?;
+        }
+        Ok(response.tasks.len())
+    })
+}
+
+fn ensure_authenticated(config: &Config) -> Result<()> {
+    if config.has_api_key() {
+        Ok(())
+    } else {
+        anyhow::bail!(
+            "No agent API key configured. Run `agentlink api-key set <sk_...>` or pass `--api-key`."
+        )
+    }
+}
@@ -0,0 +1,382 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use clap::ValueEnum;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+use std::path::Path;
+
+use crate::config::Config;
+
+/// 本地索引数据库文件名
+const INDEX_FILE: &str = "search_index.db";
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS documents (
+    rowid INTEGER PRIMARY KEY,
+    kind TEXT NOT NULL,
+    id TEXT NOT NULL,
+    conversation_id TEXT,
+    title TEXT NOT NULL,
+    author TEXT NOT NULL,
+    body TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    UNIQUE (kind, id)
+);
+
+CREATE VIRTUAL TABLE IF NOT EXISTS documents_fts USING fts5(
+    title, author, body,
+    content = 'documents',
+    content_rowid = 'rowid'
+);
+
+CREATE TRIGGER IF NOT EXISTS documents_ai AFTER INSERT ON documents BEGIN
+    INSERT INTO documents_fts (rowid, title, author, body)
+    VALUES (new.rowid, new.title, new.author, new.body);
+END;
+
+CREATE TRIGGER IF NOT EXISTS documents_ad AFTER DELETE ON documents BEGIN
+    INSERT INTO documents_fts (documents_fts, rowid, title, author, body)
+    VALUES ('delete', old.rowid, old.title, old.author, old.body);
+END;
+
+CREATE TRIGGER IF NOT EXISTS documents_au AFTER UPDATE ON documents BEGIN
+    INSERT INTO documents_fts (documents_fts, rowid, title, author, body)
+    VALUES ('delete', old.rowid, old.title, old.author, old.body);
+    INSERT INTO documents_fts (rowid, title, author, body)
+    VALUES (new.rowid, new.title, new.author, new.body);
+END;
+
+CREATE TABLE IF NOT EXISTS sync_state (
+    key TEXT PRIMARY KEY,
+    value TEXT NOT NULL
+);
+";
+
+/// 索引中的内容类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum DocKind {
+    Message,
+    Post,
+    Task,
+}
+
+impl DocKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Message => "message",
+            Self::Post => "post",
+            Self::Task => "task",
+        }
+    }
+}
+
+/// 写入索引的一条内容
+#[derive(Debug, Clone)]
+pub struct Document {
+    pub kind: DocKind,
+    pub id: String,
+    pub conversation_id: Option<String>,
+    pub title: String,
+    pub author: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 搜索条件
+#[derive(Debug, Default)]
+pub struct SearchQuery {
+    /// FTS5 查询表达式
+    pub expression: String,
+    pub kind: Option<DocKind>,
+    pub conversation_id: Option<String>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub limit: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    pub kind: String,
+    pub id: String,
+    pub conversation_id: Option<String>,
+    pub title: String,
+    pub author: String,
+    pub created_at: String,
+    pub snippet: String,
+    /// bm25 得分，越小越相关
+    pub score: f64,
+}
+
+pub struct SearchIndex {
+    conn: Connection,
+}
+
+impl SearchIndex {
+    /// 打开配置目录下的索引数据库，不存在时创建
+    pub fn open_default(config: &Config) -> Result<Self> {
+        let path = config.state_file_path(INDEX_FILE)?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create state directory: {:?}", parent))?;
+        }
+        Self::open(&path)
+    }
+
+    pub fn open(path: &Path) -> Result<Self> {
+        let conn = Connection::open(path)
+            .with_context(|| format!("Failed to open search index: {:?}", path))?;
+        Self::init(conn)
+    }
+
+    #[cfg(test)]
+    fn open_in_memory() -> Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)
+            .context("Failed to initialize search index")?;
+        Ok(Self { conn })
+    }
+
+    /// 批量写入时使用事务，显著减少磁盘同步次数
+    pub fn transaction<T>(&mut self, apply: impl FnOnce(&Self) -> Result<T>) -> Result<T> {
+        self.conn.execute_batch("BEGIN")?;
+        match apply(self) {
+            Ok(value) => {
+                self.conn.execute_batch("COMMIT")?;
+                Ok(value)
+            }
+            Err(error) => {
+                let _ = self.conn.execute_batch("ROLLBACK");
+                Err(error)
+            }
+        }
+    }
+
+    /// 新增或更新一条内容
+    pub fn upsert(&self, document: &Document) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO documents (kind, id, conversation_id, title, author, body, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+             ON CONFLICT (kind, id) DO UPDATE SET
+                 conversation_id = excluded.conversation_id,
+                 title = excluded.title,
+                 author = excluded.author,
+                 body = excluded.body,
+                 created_at = excluded.created_at",
+            params![
+                document.kind.as_str(),
+                document.id,
+                document.conversation_id,
+                document.title,
+                document.author,
+                document.body,
+                format_time(document.created_at),
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn search(&self, query: &SearchQuery) -> Result<Vec<SearchHit>> {
+        let mut statement = self.conn.prepare(
+            "SELECT d.kind, d.id, d.conversation_id, d.title, d.author, d.created_at,
+                    snippet(documents_fts, 2, '[', ']', '…', 16),
+                    bm25(documents_fts, 4.0, 2.0, 1.0) AS score
+             FROM documents_fts
+             JOIN documents d ON d.rowid = documents_fts.rowid
+             WHERE documents_fts MATCH ?1
+               AND (?2 IS NULL OR d.kind = ?2)
+               AND (?3 IS NULL OR d.conversation_id = ?3)
+               AND (?4 IS NULL OR d.created_at >= ?4)
+               AND (?5 IS NULL OR d.created_at < ?5)
+             ORDER BY score
+             LIMIT ?6",
+        )?;
+
+        let limit = i64::try_from(query.limit).unwrap_or(i64::MAX);
+        let hits = statement
+            .query_map(
+                params![
+                    query.expression,
+                    query.kind.map(DocKind::as_str),
+                    query.conversation_id,
+                    query.since.map(format_time),
+                    query.until.map(format_time),
+                    limit,
+                ],
+                |row| {
+                    Ok(SearchHit {
+                        kind: row.get(0)?,
+                        id: row.get(1)?,
+                        conversation_id: row.get(2)?,
+                        title: row.get(3)?,
+                        author: row.get(4)?,
+                        created_at: row.get(5)?,
+                        snippet: row.get(6)?,
+                        score: row.get(7)?,
+                    })
+                },
+            )?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .with_context(|| format!("Invalid search query: {}", query.expression))?;
+        Ok(hits)
+    }
+
+    /// 各类型的条目数
+    pub fn counts(&self) -> Result<Vec<(String, i64)>> {
+        let mut statement = self
+            .conn
+            .prepare("SELECT kind, COUNT(*) FROM documents GROUP BY kind ORDER BY kind")?;
+        let counts = statement
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(counts)
+    }
+
+    pub fn get_state(&self, key: &str) -> Result<Option<String>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT value FROM sync_state WHERE key = ?1",
+                params![key],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    pub fn set_state(&self, key: &str, value: &str) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO sync_state (key, value) VALUES (?1, ?2)
+             ON CONFLICT (key) DO UPDATE SET value = excluded.value",
+            params![key, value],
+        )?;
+        Ok(())
+    }
+}
+
+/// 统一为带 `Z` 的秒级 RFC 3339，保证按字符串比较即按时间比较
+pub fn format_time(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// 同步水位保留完整精度；它只会被解析后比较，截断到秒会让同一条消息每次都被当成新消息
+pub fn format_watermark(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+/// 把用户输入转为 FTS5 表达式：每个词加引号按 AND 组合，末尾 `*` 保留为前缀匹配
+pub fn to_fts_expression(input: &str) -> String {
+    input
+        .split_whitespace()
+        .map(|term| {
+            let (term, prefix) = match term.strip_suffix('*') {
+                Some(stripped) if !stripped.is_empty() => (stripped, "*"),
+                _ => (term, ""),
+            };
+            format!("\"{}\"{}", term.replace('"', "\"\""), prefix)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn document(kind: DocKind, id: &str, body: &str, day: u32) -> Document {
+        Document {
+            kind,
+            id: id.to_string(),
+            conversation_id: (kind == DocKind::Message).then(|| "c1".to_string()),
+            title: String::new(),
+            author: "alice".to_string(),
+            body: body.to_string(),
+            created_at: Utc.with_ymd_and_hms(2026, 3, day, 12, 0, 0).unwrap(),
+        }
+    }
+
+    fn query(text: &str) -> SearchQuery {
+        SearchQuery {
+            expression: to_fts_expression(text),
+            limit: 10,
+            ..SearchQuery::default()
+        }
+    }
+
+    #[test]
+    fn test_search_ranks_and_filters_documents() {
+        let index = SearchIndex::open_in_memory().unwrap();
+        index
+            .upsert(&document(
+                DocKind::Message,
+                "m1",
+                "invoice for the logo work",
+                1,
+            ))
+            .unwrap();
+        index
+            .upsert(&document(
+                DocKind::Post,
+                "p1",
+                "shipped a new logo, invoice later",
+                5,
+            ))
+            .unwrap();
+        index
+            .upsert(&document(DocKind::Task, "t1", "design a logo", 9))
+            .unwrap();
+
+        let hits = index.search(&query("logo invoice")).unwrap();
+        assert_eq!(hits.len(), 2);
+
+        let mut only_posts = query("logo");
+        only_posts.kind = Some(DocKind::Post);
+        let hits = index.search(&only_posts).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id, "p1");
+
+        let mut recent = query("logo");
+        recent.since = Some(Utc.with_ymd_and_hms(2026, 3, 4, 0, 0, 0).unwrap());
+        assert_eq!(index.search(&recent).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_upsert_replaces_indexed_text() {
+        let index = SearchIndex::open_in_memory().unwrap();
+        index
+            .upsert(&document(DocKind::Post, "p1", "first draft", 1))
+            .unwrap();
+        index
+            .upsert(&document(DocKind::Post, "p1", "final version", 1))
+            .unwrap();
+
+        assert!(index.search(&query("draft")).unwrap().is_empty());
+        assert_eq!(index.search(&query("final")).unwrap().len(), 1);
+        assert_eq!(index.counts().unwrap(), vec![("post".to_string(), 1)]);
+    }
+
+    #[test]
+    fn test_sync_state_round_trips() {
+        let index = SearchIndex::open_in_memory().unwrap();
+        assert_eq!(index.get_state("k").unwrap(), None);
+        index.set_state("k", "v1").unwrap();
+        index.set_state("k", "v2").unwrap();
+        assert_eq!(index.get_state("k").unwrap().as_deref(), Some("v2"));
+    }
+
+    #[test]
+    fn test_format_watermark_keeps_sub_second_precision() {
+        let time = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap()
+            + chrono::Duration::microseconds(123_456);
+        let parsed: DateTime<Utc> = format_watermark(time).parse().unwrap();
+        assert_eq!(parsed, time);
+    }
+
+    #[test]
+    fn test_to_fts_expression_quotes_terms() {
+        assert_eq!(to_fts_expression("logo  invoice"), "\"logo\" \"invoice\"");
+        assert_eq!(to_fts_expression("deploy* a\"b"), "\"deploy\"* \"a\"\"b\"");
+    }
+}
@@ -0,0 +1,620 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, Local, NaiveTime, Utc, Weekday};
+use colored::Colorize;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::compose::MessageDraft;
+use super::history::{message_query, SelfIdentity};
+use super::resolve::resolve_conversation;
+use crate::api::ApiClient;
+use crate::config::Config;
+use crate::models::{ConversationResponse, MessageResponse};
+use crate::utils::output::{print_error, print_success, print_warning};
+use crate::utils::time::{parse_duration, sleep_or_interrupt};
+use crate::utils::{state, template};
+
+/// 自动回复进度文件
+const AUTORESPOND_STATE_FILE: &str = "autorespond_state.json";
+
+/// 每个会话每次拉取的最新消息条数
+const SCAN_PAGE_SIZE: usize = 50;
+
+/// 规则未设置 `rate_limit` 时，同一会话两次自动回复的最小间隔
+const DEFAULT_RATE_LIMIT: &str = "10m";
+
+/// 自动回复参数
+pub struct AutorespondOptions {
+    pub rules: PathBuf,
+    pub interval: Duration,
+    pub once: bool,
+    pub dry_run: bool,
+    pub log: Option<PathBuf>,
+}
+
+/// 规则文件
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RulesFile {
+    rules: Vec<RuleConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleConfig {
+    name: String,
+    /// 会话 ID 或 @linkid；省略表示所有会话
+    #[serde(default)]
+    conversations: Vec<String>,
+    /// 发送者 linkid 或名称；省略表示任何人
+    #[serde(default)]
+    senders: Vec<String>,
+    /// 匹配消息内容的正则表达式
+    #[serde(default)]
+    pattern: Option<String>,
+    #[serde(default)]
+    schedule: Option<ScheduleConfig>,
+    /// 回复模板，可用 {sender}、{content}、{conversation}、{time}、{rule}
+    reply: String,
+    /// 是否把回复关联到触发消息
+    #[serde(default = "default_true")]
+    reply_to: bool,
+    /// 同一会话两次回复的最小间隔，例如 10m、1h
+    #[serde(default)]
+    rate_limit: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScheduleConfig {
+    /// 本地时间段，例如 "09:00-18:00"，支持跨午夜
+    hours: String,
+    /// 适用的星期（mon..sun），省略表示每天
+    #[serde(default)]
+    days: Vec<String>,
+    /// inside：时间段内匹配；outside：时间段外匹配（例如下班时间）
+    #[serde(default, rename = "match")]
+    when: ScheduleMatch,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ScheduleMatch {
+    #[default]
+    Inside,
+    Outside,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// 编译后的时间条件
+#[derive(Debug)]
+struct Schedule {
+    start: NaiveTime,
+    end: NaiveTime,
+    days: Vec<Weekday>,
+    when: ScheduleMatch,
+}
+
+impl Schedule {
+    fn parse(config: &ScheduleConfig) -> Result<Self> {
+        let (start, end) = config
+            .hours
+            .split_once('-')
+            .with_context(|| format!("Invalid hours `{}`; expected HH:MM-HH:MM", config.hours))?;
+        let parse_time = |value: &str| {
+            NaiveTime::parse_from_str(value.trim(), "%H:%M")
+                .with_context(|| format!("Invalid time `{}`; expected HH:MM", value.trim()))
+        };
+
+        let days = config
+            .days
+            .iter()
+            .map(|day| {
+                day.parse::<Weekday>()
+                    .map_err(|_| anyhow::anyhow!("Invalid day `{}`; use mon..sun", day))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            start: parse_time(start)?,
+            end: parse_time(end)?,
+            days,
+            when: config.when,
+        })
+    }
+
+    /// 时间段内（且在适用星期内）时返回 true
+    fn is_inside(&self, now: DateTime<Local>) -> bool {
+        let time = now.time();
+        let in_hours = if self.start <= self.end {
+            time >= self.start && time < self.end
+        } else {
+            // 跨午夜，例如 22:00-06:00
+            time >= self.start || time < self.end
+        };
+        in_hours && (self.days.is_empty() || self.days.contains(&now.weekday()))
+    }
+
+    fn matches(&self, now: DateTime<Local>) -> bool {
+        match self.when {
+            ScheduleMatch::Inside => self.is_inside(now),
+            ScheduleMatch::Outside => !self.is_inside(now),
+        }
+    }
+}
+
+/// 编译后的规则
+#[derive(Debug)]
+struct Rule {
+    name: String,
+    conversations: Vec<String>,
+    senders: Vec<String>,
+    pattern: Option<Regex>,
+    schedule: Option<Schedule>,
+    reply: String,
+    reply_to: bool,
+    rate_limit: ChronoDuration,
+}
+
+/// 参与规则匹配的消息字段
+#[derive(Debug)]
+struct Incoming {
+    id: String,
+    conversation_id: String,
+    sender: String,
+    sender_linkid: Option<String>,
+    content: String,
+    created_at: DateTime<Utc>,
+    /// 自己发出的消息，不触发回复
+    own: bool,
+}
+
+impl Rule {
+    fn matches(&self, message: &Incoming, now: DateTime<Local>) -> bool {
+        let sender_matches = |sender: &String| {
+            let sender = sender.trim_start_matches('@');
+            sender.eq_ignore_ascii_case(&message.sender)
+                || message
+                    .sender_linkid
+                    .as_deref()
+                    .is_some_and(|linkid| sender.eq_ignore_ascii_case(linkid))
+        };
+
+        (self.conversations.is_empty() || self.conversations.contains(&message.conversation_id))
+            && (self.senders.is_empty() || self.senders.iter().any(sender_matches))
+            && self
+                .pattern
+                .as_ref()
+                .map_or(true, |pattern| pattern.is_match(&message.content))
+            && self
+                .schedule
+                .as_ref()
+                .map_or(true, |schedule| schedule.matches(now))
+    }
+}
+
+/// 持久化的处理进度
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct AutorespondState {
+    /// 首次运行时间；没有进度的会话从这里开始处理
+    #[serde(default)]
+    initialized_at: Option<DateTime<Utc>>,
+    /// 每个会话已处理到的消息时间
+    #[serde(default)]
+    watermarks: BTreeMap<String, DateTime<Utc>>,
+    /// `规则名|会话 ID` → 最近一次回复时间
+    #[serde(default)]
+    last_replies: BTreeMap<String, DateTime<Utc>>,
+}
+
+impl AutorespondState {
+    fn rate_limited(
+        &self,
+        rule: &Rule,
+        conversation_id: &str,
+        now: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        self.last_replies
+            .get(&rate_key(&rule.name, conversation_id))
+            .copied()
+            .filter(|last| now - *last < rule.rate_limit)
+    }
+}
+
+fn rate_key(rule: &str, conversation_id: &str) -> String {
+    format!("{}|{}", rule, conversation_id)
+}
+
+/// 写入 `--log` 的一行记录
+#[derive(Debug, Serialize)]
+struct LogEntry<'a> {
+    at: DateTime<Utc>,
+    action: &'a str,
+    rule: &'a str,
+    conversation_id: &'a str,
+    message_id: &'a str,
+    sender: &'a str,
+    reply: Option<&'a str>,
+}
+
+pub async fn run(client: &ApiClient, config: &Config, options: AutorespondOptions) -> Result<()> {
+    let rules = load_rules(client, &options.rules).await?;
+    let identity = SelfIdentity::fetch(client).await?;
+
+    let mut state: AutorespondState = state::load(config, AUTORESPOND_STATE_FILE)?;
+    if state.initialized_at.is_none() {
+        state.initialized_at = Some(Utc::now());
+    }
+
+    if !options.once {
+        println!(
+            "{}",
+            format!(
+                "Auto-responding with {} rule(s), checking every {}s{}. Press Ctrl+C to exit.",
+                rules.len(),
+                options.interval.as_secs(),
+                if options.dry_run { " (dry run)" } else { "" }
+            )
+            .cyan()
+        );
+    }
+
+    loop {
+        let conversations = match client.list_all_conversations().await {
+            Ok(conversations) => conversations,
+            Err(error) => {
+                print_error(&format!("Failed to list conversations: {}", error));
+                Vec::new()
+            }
+        };
+
+        for conversation in &conversations {
+            let conversation_id = conversation.id.to_string();
+            let watermark = state
+                .watermarks
+                .get(&conversation_id)
+                .copied()
+                .or(state.initialized_at)
+                .unwrap_or_else(Utc::now);
+            if conversation
+                .last_message_at
+                .is_some_and(|last| last <= watermark)
+            {
+                continue;
+            }
+
+            let batch = match client
+                .get_messages(&conversation_id, message_query(None, SCAN_PAGE_SIZE)?)
+                .await
+            {
+                Ok(batch) => batch,
+                Err(error) => {
+                    print_error(&format!(
+                        "Failed to get messages for {}: {}",
+                        conversation_id, error
+                    ));
+                    continue;
+                }
+            };
+
+            let mut incoming: Vec<Incoming> = batch
+                .iter()
+                .filter(|message| message.created_at > watermark)
+                .map(|message| Incoming::from_message(conversation, message, &identity))
+                .collect();
+            incoming.sort_by_key(|message| message.created_at);
+
+            // 进度只推进到最后一条处理完的消息；回复发送失败时停在它之前，下一轮重试
+            let mut handled = None;
+            for message in &incoming {
+                if !message.own && !respond(client, &rules, &mut state, message, &options).await? {
+                    break;
+                }
+                handled = Some(message.created_at);
+            }
+
+            if let Some(handled) = handled {
+                state.watermarks.insert(conversation_id.clone(), handled);
+            }
+        }
+
+        // 试运行不保存进度，下次运行会重新评估同样的消息
+        if !options.dry_run {
+            state::save(config, AUTORESPOND_STATE_FILE, &state)?;
+        }
+
+        if options.once || !sleep_or_interrupt(options.interval).await {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// 按规则处理一条消息；回复发送失败时返回 false
+async fn respond(
+    client: &ApiClient,
+    rules: &[Rule],
+    state: &mut AutorespondState,
+    message: &Incoming,
+    options: &AutorespondOptions,
+) -> Result<bool> {
+    let Some(rule) = rules
+        .iter()
+        .find(|rule| rule.matches(message, Local::now()))
+    else {
+        return Ok(true);
+    };
+
+    let now = Utc::now();
+    if let Some(last) = state.rate_limited(rule, &message.conversation_id, now) {
+        write_log(options, "rate_limited", rule, message, None)?;
+        println!(
+            "{}",
+            format!(
+                "[{}] Skipped {} in {}: last reply at {}",
+                rule.name,
+                message.id,
+                message.conversation_id,
+                last.with_timezone(&Local).format("%H:%M")
+            )
+            .dimmed()
+        );
+        return Ok(true);
+    }
+
+    let reply = template::render(
+        &rule.reply,
+        &[
+            ("sender", message.sender.clone()),
+            ("content", message.content.clone()),
+            ("conversation", message.conversation_id.clone()),
+            ("time", Local::now().format("%H:%M").to_string()),
+            ("rule", rule.name.clone()),
+        ],
+    );
+
+    if options.dry_run {
+        write_log(options, "dry_run", rule, message, Some(&reply))?;
+        print_warning(&format!(
+            "[dry-run] [{}] {} → {}: {}",
+            rule.name, message.sender, message.conversation_id, reply
+        ));
+        // 试运行也按速率限制记录，便于观察规则实际触发的频率
+        state
+            .last_replies
+            .insert(rate_key(&rule.name, &message.conversation_id), now);
+        return Ok(true);
+    }
+
+    let request = MessageDraft {
+        content: reply.clone(),
+        reply_to: rule.reply_to.then(|| message.id.clone()),
+        ..MessageDraft::default()
+    }
+    .into_request()?;
+
+    match client.send_message(&message.conversation_id, request).await {
+        Ok(_) => {
+            state
+                .last_replies
+                .insert(rate_key(&rule.name, &message.conversation_id), now);
+            write_log(options, "sent", rule, message, Some(&reply))?;
+            print_success(&format!(
+                "[{}] Replied to {} in {}",
+                rule.name, message.sender, message.conversation_id
+            ));
+            Ok(true)
+        }
+        Err(error) => {
+            write_log(options, "failed", rule, message, Some(&reply))?;
+            print_error(&format!(
+                "[{}] Failed to reply in {} (will retry): {}",
+                rule.name, message.conversation_id, error
+            ));
+            Ok(false)
+        }
+    }
+}
+
+impl Incoming {
+    /// 发送者 linkid 从会话参与者中查找，自己发出的消息按用户 ID 识别
+    fn from_message(
+        conversation: &ConversationResponse,
+        message: &MessageResponse,
+        identity: &SelfIdentity,
+    ) -> Self {
+        Self {
+            id: message.id.to_string(),
+            conversation_id: conversation.id.to_string(),
+            sender: message.sender_name.clone(),
+            sender_linkid: conversation
+                .participants
+                .iter()
+                .find(|participant| participant.user_id == message.sender_id)
+                .map(|participant| participant.linkid.clone()),
+            content: message.content.clone(),
+            created_at: message.created_at,
+            own: identity.sent(message),
+        }
+    }
+}
+
+async fn load_rules(client: &ApiClient, path: &Path) -> Result<Vec<Rule>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read rules file: {:?}", path))?;
+    let file: RulesFile = serde_yaml::from_str(&content)
+        .with_context(|| format!("Failed to parse rules file: {:?}", path))?;
+
+    let mut rules = Vec::with_capacity(file.rules.len());
+    for config in file.rules {
+        let mut conversations = Vec::with_capacity(config.conversations.len());
+        for conversation in &config.conversations {
+            conversations.push(resolve_conversation(client, conversation, false).await?);
+        }
+        rules.push(compile_rule(config, conversations)?);
+    }
+
+    if rules.is_empty() {
+        anyhow::bail!("The rules file {:?} defines no rules.", path);
+    }
+    Ok(rules)
+}
+
+fn compile_rule(config: RuleConfig, conversations: Vec<String>) -> Result<Rule> {
+    let context = |what: &str| format!("Invalid {} in rule `{}`", what, config.name);
+
+    let pattern = config
+        .pattern
+        .as_deref()
+        .map(Regex::new)
+        .transpose()
+        .with_context(|| context("pattern"))?;
+    let schedule = config
+        .schedule
+        .as_ref()
+        .map(Schedule::parse)
+        .transpose()
+        .with_context(|| context("schedule"))?;
+    let rate_limit = parse_duration(config.rate_limit.as_deref().unwrap_or(DEFAULT_RATE_LIMIT))
+        .and_then(|duration| ChronoDuration::from_std(duration).map_err(Into::into))
+        .with_context(|| context("rate_limit"))?;
+
+    Ok(Rule {
+        name: config.name,
+        conversations,
+        senders: config.senders,
+        pattern,
+        schedule,
+        reply: config.reply,
+        reply_to: config.reply_to,
+        rate_limit,
+    })
+}
+
+fn write_log(
+    options: &AutorespondOptions,
+    action: &str,
+    rule: &Rule,
+    message: &Incoming,
+    reply: Option<&str>,
+) -> Result<()> {
+    let Some(path) = &options.log else {
+        return Ok(());
+    };
+
+    let entry = LogEntry {
+        at: Utc::now(),
+        action,
+        rule: &rule.name,
+        conversation_id: &message.conversation_id,
+        message_id: &message.id,
+        sender: &message.sender,
+        reply,
+    };
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open log file: {:?}", path))?;
+    writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn rule(yaml: &str) -> Rule {
+        let config: RuleConfig = serde_yaml::from_str(yaml).unwrap();
+        let conversations = config.conversations.clone();
+        compile_rule(config, conversations).unwrap()
+    }
+
+    fn incoming(sender: &str, content: &str) -> Incoming {
+        Incoming {
+            id: "m1".to_string(),
+            conversation_id: "c1".to_string(),
+            sender: sender.to_string(),
+            sender_linkid: None,
+            content: content.to_string(),
+            created_at: Utc::now(),
+            own: false,
+        }
+    }
+
+    fn local(weekday_offset: u32, hour: u32) -> DateTime<Local> {
+        // 2026-03-02 是星期一
+        Local
+            .with_ymd_and_hms(2026, 3, 2 + weekday_offset, hour, 30, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_rule_matches_sender_and_pattern() {
+        let rule = rule(
+            "name: pricing\nsenders: ['@Alice']\npattern: '(?i)price|quote'\nreply: 'Hi {sender}'",
+        );
+        let now = local(0, 10);
+
+        assert!(rule.matches(&incoming("alice", "What's the PRICE?"), now));
+        assert!(!rule.matches(&incoming("alice", "hello"), now));
+        assert!(!rule.matches(&incoming("bob", "price?"), now));
+    }
+
+    #[test]
+    fn test_schedule_outside_working_hours() {
+        let rule = rule(
+            "name: after-hours\nschedule:\n  hours: '09:00-18:00'\n  days: [mon, tue, wed, thu, fri]\n  match: outside\nreply: 'Back soon'",
+        );
+        let message = incoming("client", "hi");
+
+        assert!(!rule.matches(&message, local(0, 10)));
+        assert!(rule.matches(&message, local(0, 20)));
+        // 周六全天都在工作时间外
+        assert!(rule.matches(&message, local(5, 10)));
+    }
+
+    #[test]
+    fn test_schedule_spanning_midnight() {
+        let schedule = Schedule::parse(&ScheduleConfig {
+            hours: "22:00-06:00".to_string(),
+            days: Vec::new(),
+            when: ScheduleMatch::Inside,
+        })
+        .unwrap();
+
+        assert!(schedule.matches(local(0, 23)));
+        assert!(schedule.matches(local(0, 3)));
+        assert!(!schedule.matches(local(0, 12)));
+    }
+
+    #[test]
+    fn test_rate_limit_is_per_rule_and_conversation() {
+        let rule = rule("name: ack\nreply: ok\nrate_limit: 1h");
+        let now = Utc::now();
+        let mut state = AutorespondState::default();
+        state
+            .last_replies
+            .insert(rate_key("ack", "c1"), now - ChronoDuration::minutes(30));
+
+        assert!(state.rate_limited(&rule, "c1", now).is_some());
+        assert!(state.rate_limited(&rule, "c2", now).is_none());
+        assert!(state
+            .rate_limited(&rule, "c1", now + ChronoDuration::hours(1))
+            .is_none());
+    }
+
+    #[test]
+    fn test_unknown_rule_fields_are_rejected() {
+        assert!(serde_yaml::from_str::<RuleConfig>("name: x\nreply: y\nsender: [a]").is_err());
+    }
+}
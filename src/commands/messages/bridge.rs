@@ -0,0 +1,382 @@
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use colored::Colorize;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{ChildStdin, Command};
+use tokio::sync::{mpsc, oneshot};
+
+use super::compose::MessageDraft;
+use super::history::{message_query, SelfIdentity};
+use super::MessageKindArg;
+use crate::api::ApiClient;
+use crate::models::{MessageResponse, MessageType};
+use crate::utils::output::{print_error, print_success, print_warning};
+
+/// 每次轮询拉取的最新消息条数
+const POLL_PAGE_SIZE: usize = 50;
+
+/// 子进程运行超过该时长后，重启计数清零
+const HEALTHY_RUN_SECS: u64 = 60;
+
+/// 重启等待时间上限
+const MAX_BACKOFF_SECS: u64 = 30;
+
+/// 桥接参数
+pub struct BridgeOptions {
+    pub conversation_id: String,
+    pub command: Vec<String>,
+    pub interval: Duration,
+    /// 等待写入子进程的消息上限，满了之后暂停拉取
+    pub queue: usize,
+    pub max_restarts: u32,
+    /// 子进程以 0 退出时结束桥接，而不是重启
+    pub exit_on_success: bool,
+}
+
+/// 子进程输出的一行
+#[derive(Debug, Deserialize)]
+struct BridgeOutput {
+    content: String,
+    #[serde(default)]
+    reply_to: Option<String>,
+    #[serde(default)]
+    kind: Option<String>,
+    #[serde(default)]
+    metadata: Option<Value>,
+}
+
+impl BridgeOutput {
+    fn parse(line: &str) -> Result<Self> {
+        let output: Self = serde_json::from_str(line)
+            .context("expected a JSON object with at least a `content` field")?;
+        if output.content.trim().is_empty() {
+            anyhow::bail!("`content` is empty");
+        }
+        Ok(output)
+    }
+
+    fn into_draft(self) -> Result<MessageDraft> {
+        Ok(MessageDraft {
+            content: self.content,
+            kind: self
+                .kind
+                .as_deref()
+                .map(|kind| {
+                    MessageKindArg::from_str(kind.trim(), true)
+                        .map(MessageType::from)
+                        .map_err(|_| anyhow::anyhow!("unknown message kind `{}`", kind))
+                })
+                .transpose()?,
+            reply_to: self.reply_to,
+            metadata: self.metadata,
+            attachments: Vec::new(),
+        })
+    }
+}
+
+/// 子进程一次运行的结束方式
+enum ChildExit {
+    Success,
+    Failure(String),
+    Interrupted,
+}
+
+pub async fn run(client: &ApiClient, options: BridgeOptions) -> Result<()> {
+    let Some((program, args)) = options.command.split_first() else {
+        anyhow::bail!("No command given. Usage: agentlink messages bridge <id> -- <command>");
+    };
+
+    let identity = SelfIdentity::fetch(client).await?;
+    let (sender, mut receiver) = mpsc::channel::<Value>(options.queue.max(1));
+
+    // 启动时已有的消息只标记为已读，不重放给子进程
+    let mut seen = HashSet::new();
+    let initial = client
+        .get_messages(
+            &options.conversation_id,
+            message_query(None, POLL_PAGE_SIZE)?,
+        )
+        .await?;
+    seen.extend(initial.iter().map(|message| message.id.to_string()));
+
+    let poller = tokio::spawn(poll_messages(
+        client.clone(),
+        options.conversation_id.clone(),
+        identity.clone(),
+        seen,
+        options.interval,
+        sender,
+    ));
+
+    println!(
+        "{}",
+        format!(
+            "Bridging conversation {} to `{}`. Press Ctrl+C to exit.",
+            options.conversation_id,
+            options.command.join(" ")
+        )
+        .cyan()
+    );
+
+    let mut pending: Option<Value> = None;
+    let mut failures = 0;
+    let result = loop {
+        let mut child = Command::new(program)
+            .args(args)
+            .env("AGENTLINK_CONVERSATION_ID", &options.conversation_id)
+            .env("AGENTLINK_LINKID", &identity.linkid)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .kill_on_drop(true)
+            .spawn()
+            .with_context(|| format!("Failed to start `{}`", program))?;
+        let started = Instant::now();
+
+        let stdin = child.stdin.take().context("Child stdin is not piped")?;
+        let stdout = child.stdout.take().context("Child stdout is not piped")?;
+        let mut lines = BufReader::new(stdout).lines();
+
+        let (stop, stopped) = oneshot::channel();
+        let writer = tokio::spawn(feed_child(receiver, stdin, pending.take(), stopped));
+
+        let exit = loop {
+            tokio::select! {
+                line = lines.next_line() => match line {
+                    Ok(Some(line)) => forward_output(client, &options.conversation_id, &line).await,
+                    // 标准输出关闭后等待进程退出
+                    Ok(None) | Err(_) => {
+                        break match child.wait().await {
+                            Ok(status) if status.success() => ChildExit::Success,
+                            Ok(status) => ChildExit::Failure(status.to_string()),
+                            Err(error) => ChildExit::Failure(error.to_string()),
+                        };
+                    }
+                },
+                status = child.wait() => {
+                    // 进程已退出，转发缓冲区中剩余的输出
+                    while let Ok(Some(line)) = lines.next_line().await {
+                        forward_output(client, &options.conversation_id, &line).await;
+                    }
+                    break match status {
+                        Ok(status) if status.success() => ChildExit::Success,
+                        Ok(status) => ChildExit::Failure(status.to_string()),
+                        Err(error) => ChildExit::Failure(error.to_string()),
+                    };
+                }
+                _ = tokio::signal::ctrl_c() => {
+                    let _ = child.kill().await;
+                    break ChildExit::Interrupted;
+                }
+            }
+        };
+
+        let _ = stop.send(());
+        let (returned, undelivered) = writer.await.context("Bridge writer task failed")?;
+        receiver = returned;
+        pending = undelivered;
+
+        match exit {
+            ChildExit::Interrupted => {
+                println!();
+                break Ok(());
+            }
+            ChildExit::Success if options.exit_on_success => {
+                print_success("Bridge process exited normally.");
+                break Ok(());
+            }
+            ChildExit::Success => {
+                // 正常退出不计入连续失败，稍等后重启，避免立即退出的程序空转
+                failures = 0;
+                let delay = restart_backoff(1);
+                print_warning(&format!(
+                    "`{}` exited normally; restarting in {}s.",
+                    program,
+                    delay.as_secs()
+                ));
+                if !crate::utils::time::sleep_or_interrupt(delay).await {
+                    break Ok(());
+                }
+            }
+            ChildExit::Failure(reason) => {
+                if started.elapsed() >= Duration::from_secs(HEALTHY_RUN_SECS) {
+                    failures = 0;
+                }
+                failures += 1;
+                if failures > options.max_restarts {
+                    break Err(anyhow::anyhow!(
+                        "`{}` failed {} times in a row (last: {}); giving up.",
+                        program,
+                        failures,
+                        reason
+                    ));
+                }
+
+                let backoff = restart_backoff(failures);
+                print_warning(&format!(
+                    "`{}` exited ({}); restarting in {}s ({}/{}).",
+                    program,
+                    reason,
+                    backoff.as_secs(),
+                    failures,
+                    options.max_restarts
+                ));
+                if !crate::utils::time::sleep_or_interrupt(backoff).await {
+                    break Ok(());
+                }
+            }
+        }
+    };
+
+    poller.abort();
+    result
+}
+
+/// 轮询新消息放入有界队列；队列满时 `send` 会等待，从而暂停拉取
+async fn poll_messages(
+    client: ApiClient,
+    conversation_id: String,
+    identity: SelfIdentity,
+    mut seen: HashSet<String>,
+    interval: Duration,
+    sender: mpsc::Sender<Value>,
+) {
+    loop {
+        tokio::time::sleep(interval).await;
+
+        let query = match message_query(None, POLL_PAGE_SIZE) {
+            Ok(query) => query,
+            Err(error) => {
+                print_error(&format!("Failed to build message query: {}", error));
+                return;
+            }
+        };
+        let mut batch: Vec<MessageResponse> =
+            match client.get_messages(&conversation_id, query).await {
+                Ok(batch) => batch,
+                Err(error) => {
+                    print_warning(&format!("Polling failed: {}", error));
+                    continue;
+                }
+            };
+        batch.retain(|message| seen.insert(message.id.to_string()));
+        batch.sort_by_key(|message| message.created_at);
+
+        for message in batch {
+            if identity.sent(&message) {
+                continue;
+            }
+            let Ok(mut value) = serde_json::to_value(&message) else {
+                continue;
+            };
+            if let Value::Object(object) = &mut value {
+                object
+                    .entry("conversation_id")
+                    .or_insert_with(|| Value::String(conversation_id.clone()));
+            }
+            if sender.send(value).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+/// 把队列中的消息逐行写入子进程；子进程退出或收到停止信号时归还队列和未送达的消息
+async fn feed_child(
+    mut receiver: mpsc::Receiver<Value>,
+    mut stdin: ChildStdin,
+    mut pending: Option<Value>,
+    mut stopped: oneshot::Receiver<()>,
+) -> (mpsc::Receiver<Value>, Option<Value>) {
+    loop {
+        let message = match pending.take() {
+            Some(message) => message,
+            None => tokio::select! {
+                message = receiver.recv() => match message {
+                    Some(message) => message,
+                    None => return (receiver, None),
+                },
+                _ = &mut stopped => return (receiver, None),
+            },
+        };
+
+        let mut line = match serde_json::to_string(&message) {
+            Ok(line) => line,
+            Err(_) => continue,
+        };
+        line.push('\n');
+
+        // 子进程读取缓慢时写入会阻塞，这里同时监听停止信号
+        let written = tokio::select! {
+            result = async {
+                stdin.write_all(line.as_bytes()).await?;
+                stdin.flush().await
+            } => result.is_ok(),
+            _ = &mut stopped => false,
+        };
+        if !written {
+            return (receiver, Some(message));
+        }
+    }
+}
+
+async fn forward_output(client: &ApiClient, conversation_id: &str, line: &str) {
+    let line = line.trim();
+    if line.is_empty() {
+        return;
+    }
+
+    let request = match BridgeOutput::parse(line).and_then(BridgeOutput::into_draft) {
+        Ok(draft) => draft.into_request(),
+        Err(error) => {
+            print_warning(&format!("Ignored bridge output ({}): {}", error, line));
+            return;
+        }
+    };
+
+    match request {
+        Ok(request) => match client.send_message(conversation_id, request).await {
+            Ok(message) => println!("{}", format!("→ sent {}", message.id).dimmed()),
+            Err(error) => print_error(&format!("Failed to send message: {}", error)),
+        },
+        Err(error) => print_warning(&format!("Ignored bridge output ({}): {}", error, line)),
+    }
+}
+
+fn restart_backoff(failures: u32) -> Duration {
+    let seconds = 1u64
+        .checked_shl(failures.saturating_sub(1))
+        .unwrap_or(MAX_BACKOFF_SECS);
+    Duration::from_secs(seconds.min(MAX_BACKOFF_SECS))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bridge_output_requires_content() {
+        let output =
+            BridgeOutput::parse(r#"{"content": "done", "reply_to": "m1", "metadata": {"a": 1}}"#)
+                .unwrap();
+        assert_eq!(output.reply_to.as_deref(), Some("m1"));
+        assert_eq!(output.metadata, Some(serde_json::json!({"a": 1})));
+
+        assert!(BridgeOutput::parse("plain text").is_err());
+        assert!(BridgeOutput::parse(r#"{"content": "  "}"#).is_err());
+        assert!(BridgeOutput::parse(r#"{"reply_to": "m1"}"#).is_err());
+    }
+
+    #[test]
+    fn test_restart_backoff_doubles_up_to_cap() {
+        assert_eq!(restart_backoff(1), Duration::from_secs(1));
+        assert_eq!(restart_backoff(3), Duration::from_secs(4));
+        assert_eq!(restart_backoff(10), Duration::from_secs(MAX_BACKOFF_SECS));
+        assert_eq!(restart_backoff(100), Duration::from_secs(MAX_BACKOFF_SECS));
+    }
+}
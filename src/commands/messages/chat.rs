@@ -0,0 +1,361 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::io::Write;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, BufReader};
+
+use super::compose::MessageDraft;
+use super::history::{self, message_query};
+use crate::api::ApiClient;
+use crate::models::{MessageResponse, SendMessageRequest};
+use crate::utils::output::{print_error, print_success, print_warning};
+
+/// 每次轮询拉取的最新消息条数
+const POLL_PAGE_SIZE: usize = 20;
+
+/// `/history` 未指定条数时显示的消息数
+const DEFAULT_HISTORY: usize = 20;
+
+/// 聊天参数
+pub struct ChatOptions {
+    pub conversation_id: String,
+    pub history: usize,
+    pub interval: Duration,
+}
+
+/// 解析后的一行输入
+#[derive(Debug, PartialEq)]
+enum ChatInput {
+    Empty,
+    Message(String),
+    Reply(Option<String>),
+    Attach(String),
+    History(usize),
+    Help,
+    Quit,
+    Invalid(String),
+}
+
+fn parse_input(line: &str) -> ChatInput {
+    let line = line.trim();
+    if line.is_empty() {
+        return ChatInput::Empty;
+    }
+
+    // 以 `//` 开头表示发送以 `/` 开头的普通文本
+    if let Some(text) = line.strip_prefix("//") {
+        return ChatInput::Message(format!("/{}", text));
+    }
+    let Some(command) = line.strip_prefix('/') else {
+        return ChatInput::Message(line.to_string());
+    };
+
+    let (name, argument) = match command.split_once(char::is_whitespace) {
+        Some((name, argument)) => (name, argument.trim()),
+        None => (command, ""),
+    };
+
+    match name {
+        "reply" | "r" => ChatInput::Reply((!argument.is_empty()).then(|| argument.to_string())),
+        "attach" | "a" if argument.is_empty() => {
+            ChatInput::Invalid("Usage: /attach <url>".to_string())
+        }
+        "attach" | "a" => match attachment_url(argument) {
+            Ok(url) => ChatInput::Attach(url),
+            Err(error) => ChatInput::Invalid(error.to_string()),
+        },
+        "history" | "h" if argument.is_empty() => ChatInput::History(DEFAULT_HISTORY),
+        "history" | "h" => match argument.parse() {
+            Ok(count) if count > 0 => ChatInput::History(count),
+            _ => ChatInput::Invalid("Usage: /history [count]".to_string()),
+        },
+        "help" | "?" => ChatInput::Help,
+        "quit" | "q" | "exit" => ChatInput::Quit,
+        other => ChatInput::Invalid(format!("Unknown command `/{}`. Type /help.", other)),
+    }
+}
+
+/// 服务端没有上传接口，`/attach` 只接受已托管文件的 http(s) 链接
+fn attachment_url(value: &str) -> Result<String> {
+    let url = url::Url::parse(value)
+        .map_err(|error| anyhow::anyhow!("Invalid attachment URL `{}`: {}", value, error))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        anyhow::bail!(
+            "Attachment `{}` must be an http(s) URL; host the file elsewhere first.",
+            value
+        );
+    }
+    Ok(url.to_string())
+}
+
+/// 下一条消息的待发送状态
+#[derive(Default)]
+struct Draft {
+    reply_to: Option<String>,
+    /// 附件链接，发送时写入 metadata 的 `attachments` 字段
+    attachments: Vec<String>,
+}
+
+pub async fn run(client: &ApiClient, options: ChatOptions) -> Result<()> {
+    let conversation_id = options.conversation_id.as_str();
+    let mut seen: HashSet<String> = HashSet::new();
+
+    let recent =
+        match history::fetch_history(client, conversation_id, None, Some(options.history)).await {
+            Ok(messages) => messages,
+            Err(error) => {
+                print_error(&format!("Failed to get messages: {}", error));
+                return Ok(());
+            }
+        };
+
+    println!(
+        "\n{} {}\n",
+        "Chat".bold().underline(),
+        conversation_id.dimmed()
+    );
+    for message in &recent {
+        seen.insert(message.id.to_string());
+        print_message(message);
+    }
+    println!(
+        "\n{}\n",
+        "Type a message and press Enter. /help lists commands, /quit exits.".dimmed()
+    );
+
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    let mut ticker = tokio::time::interval(options.interval);
+    ticker.tick().await;
+    let mut draft = Draft::default();
+    prompt(&draft);
+
+    loop {
+        tokio::select! {
+            line = lines.next_line() => {
+                // 标准输入关闭（Ctrl+D）时退出
+                let Some(line) = line? else { break };
+
+                match parse_input(&line) {
+                    ChatInput::Empty => {}
+                    ChatInput::Quit => break,
+                    ChatInput::Help => print_help(),
+                    ChatInput::Invalid(message) => print_warning(&message),
+                    ChatInput::Reply(Some(id)) => {
+                        println!("{}", format!("Replying to {}", id).dimmed());
+                        draft.reply_to = Some(id);
+                    }
+                    ChatInput::Reply(None) => {
+                        println!("{}", "Reply target cleared.".dimmed());
+                        draft.reply_to = None;
+                    }
+                    ChatInput::Attach(url) => {
+                        print_success(&format!(
+                            "Attached {} ({} pending).",
+                            url,
+                            draft.attachments.len() + 1
+                        ));
+                        draft.attachments.push(url);
+                    }
+                    ChatInput::History(count) => {
+                        match history::fetch_history(client, conversation_id, None, Some(count))
+                            .await
+                        {
+                            Ok(messages) => {
+                                println!();
+                                for message in &messages {
+                                    seen.insert(message.id.to_string());
+                                    print_message(message);
+                                }
+                                println!();
+                            }
+                            Err(error) => {
+                                print_error(&format!("Failed to get messages: {}", error))
+                            }
+                        }
+                    }
+                    ChatInput::Message(content) => {
+                        let request = build_request(content, &draft)?;
+                        match client.send_message(conversation_id, request).await {
+                            Ok(message) => {
+                                seen.insert(message.id.to_string());
+                                print_message(&message);
+                                draft = Draft::default();
+                            }
+                            Err(error) => {
+                                print_error(&format!("Failed to send message: {}", error))
+                            }
+                        }
+                    }
+                }
+                prompt(&draft);
+            }
+            _ = ticker.tick() => {
+                match poll_new(client, conversation_id, &mut seen).await {
+                    Ok(messages) if !messages.is_empty() => {
+                        // 清掉当前提示符所在行后再输出新消息
+                        print!("\r\x1b[2K");
+                        for message in &messages {
+                            print_message(message);
+                        }
+                        prompt(&draft);
+                    }
+                    Ok(_) => {}
+                    Err(error) => print_warning(&format!("Polling failed: {}", error)),
+                }
+            }
+            _ = tokio::signal::ctrl_c() => {
+                println!();
+                break;
+            }
+        }
+    }
+
+    println!("{}", "Left chat.".dimmed());
+    Ok(())
+}
+
+/// 拉取最新一页消息，返回尚未显示过的部分（按时间升序）
+async fn poll_new(
+    client: &ApiClient,
+    conversation_id: &str,
+    seen: &mut HashSet<String>,
+) -> Result<Vec<MessageResponse>> {
+    let batch = client
+        .get_messages(conversation_id, message_query(None, POLL_PAGE_SIZE)?)
+        .await?;
+
+    let mut fresh: Vec<MessageResponse> = batch
+        .into_iter()
+        .filter(|message| seen.insert(message.id.to_string()))
+        .collect();
+    fresh.sort_by_key(|message| message.created_at);
+    Ok(fresh)
+}
+
+fn build_request(content: String, draft: &Draft) -> Result<SendMessageRequest> {
+    MessageDraft {
+        content,
+        reply_to: draft.reply_to.clone(),
+        metadata: attachment_metadata(&draft.attachments),
+        ..MessageDraft::default()
+    }
+    .into_request()
+}
+
+fn attachment_metadata(urls: &[String]) -> Option<Value> {
+    if urls.is_empty() {
+        return None;
+    }
+    let attachments: Vec<Value> = urls.iter().map(|url| json!({ "url": url })).collect();
+    Some(json!({ "attachments": attachments }))
+}
+
+fn print_message(message: &MessageResponse) {
+    let time = message.created_at.format("%m-%d %H:%M").to_string();
+    let id = message.id.to_string();
+    println!(
+        "{} {} {}: {}",
+        time.dimmed(),
+        id.get(..8).unwrap_or(&id).dimmed(),
+        message.sender_name.cyan(),
+        message.content
+    );
+}
+
+fn prompt(draft: &Draft) {
+    let mut status = Vec::new();
+    if let Some(reply_to) = &draft.reply_to {
+        status.push(format!("↪ {}", reply_to.get(..8).unwrap_or(reply_to)));
+    }
+    if !draft.attachments.is_empty() {
+        status.push(format!("📎 {}", draft.attachments.len()));
+    }
+
+    if status.is_empty() {
+        print!("{} ", ">".green().bold());
+    } else {
+        print!(
+            "{} {} ",
+            format!("[{}]", status.join(" ")).dimmed(),
+            ">".green().bold()
+        );
+    }
+    let _ = std::io::stdout().flush();
+}
+
+fn print_help() {
+    println!("\n{}:", "Commands".bold());
+    println!(
+        "  {}  reply to a message (no id clears the target)",
+        "/reply <id>".cyan()
+    );
+    println!(
+        "  {}  attach a hosted file link to the next message",
+        "/attach <url>".cyan()
+    );
+    println!(
+        "  {}  show recent messages (default {})",
+        "/history [n]".cyan(),
+        DEFAULT_HISTORY
+    );
+    println!(
+        "  {}  leave the chat (also Ctrl+C / Ctrl+D)",
+        "/quit".cyan()
+    );
+    println!(
+        "  Start a line with {} to send text beginning with `/`.\n",
+        "//".cyan()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input_recognizes_commands() {
+        assert_eq!(parse_input("  "), ChatInput::Empty);
+        assert_eq!(
+            parse_input("hello"),
+            ChatInput::Message("hello".to_string())
+        );
+        assert_eq!(
+            parse_input("/reply abc"),
+            ChatInput::Reply(Some("abc".to_string()))
+        );
+        assert_eq!(parse_input("/reply"), ChatInput::Reply(None));
+        assert_eq!(
+            parse_input("/attach https://cdn.example.com/report.pdf"),
+            ChatInput::Attach("https://cdn.example.com/report.pdf".to_string())
+        );
+        assert_eq!(parse_input("/history"), ChatInput::History(DEFAULT_HISTORY));
+        assert_eq!(parse_input("/history 5"), ChatInput::History(5));
+        assert_eq!(parse_input("/quit"), ChatInput::Quit);
+    }
+
+    #[test]
+    fn test_parse_input_handles_escapes_and_errors() {
+        assert_eq!(
+            parse_input("//shrug"),
+            ChatInput::Message("/shrug".to_string())
+        );
+        assert!(matches!(parse_input("/attach"), ChatInput::Invalid(_)));
+        assert!(matches!(
+            parse_input("/attach ./report.pdf"),
+            ChatInput::Invalid(_)
+        ));
+        assert!(matches!(parse_input("/history x"), ChatInput::Invalid(_)));
+        assert!(matches!(parse_input("/nope"), ChatInput::Invalid(_)));
+    }
+
+    #[test]
+    fn test_attachment_metadata_lists_urls() {
+        assert_eq!(attachment_metadata(&[]), None);
+        assert_eq!(
+            attachment_metadata(&["https://cdn.example.com/a.pdf".to_string()]),
+            Some(json!({"attachments": [{"url": "https://cdn.example.com/a.pdf"}]}))
+        );
+    }
+}
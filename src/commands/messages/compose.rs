@@ -0,0 +1,82 @@
+use anyhow::{Context, Result};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::models::{MessageType, SendMessageRequest};
+
+/// 组装一条待发送的消息
+#[derive(Default)]
+pub struct MessageDraft {
+    pub content: String,
+    /// 省略时为 `MessageType::Text`
+    pub kind: Option<MessageType>,
+    pub reply_to: Option<String>,
+    pub metadata: Option<Value>,
+}
+
+impl MessageDraft {
+    pub fn into_request(self) -> Result<SendMessageRequest> {
+        Ok(SendMessageRequest {
+            content: self.content,
+            kind: Some(self.kind.unwrap_or(MessageType::Text)),
+            metadata: self.metadata,
+            reply_to: self
+                .reply_to
+                .map(|id| {
+                    Uuid::parse_str(id.trim()).with_context(|| {
+                        format!("Invalid message ID for --reply-to: `{}` is not a UUID", id)
+                    })
+                })
+                .transpose()?,
+        })
+    }
+}
+
+/// 解析 `--metadata`：内联 JSON，或 `@path` 从文件读取
+pub fn parse_metadata(value: &str) -> Result<Value> {
+    let (text, source) = match value.strip_prefix('@') {
+        Some(path) => (
+            std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read metadata file: {}", path))?,
+            path,
+        ),
+        None => (value.to_string(), "--metadata"),
+    };
+
+    serde_json::from_str(&text).with_context(|| format!("Invalid JSON in {}", source))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_metadata_reads_inline_json_and_files() {
+        assert_eq!(parse_metadata(r#"{"a": 1}"#).unwrap(), json!({"a": 1}));
+        assert!(parse_metadata("{not json").is_err());
+
+        let path =
+            std::env::temp_dir().join(format!("agentlink-meta-{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(&path, r#"{"receipt": true}"#).unwrap();
+        let value = parse_metadata(&format!("@{}", path.display())).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(value, json!({"receipt": true}));
+    }
+
+    #[test]
+    fn test_into_request_rejects_invalid_reply_to() {
+        let draft = |reply_to: &str| MessageDraft {
+            content: "ok".to_string(),
+            reply_to: Some(reply_to.to_string()),
+            ..MessageDraft::default()
+        };
+
+        let id = Uuid::new_v4();
+        let request = draft(&format!(" {} ", id)).into_request().unwrap();
+        assert_eq!(request.reply_to, Some(id));
+
+        let error = draft("m-42").into_request().unwrap_err();
+        assert!(error.to_string().contains("--reply-to"));
+    }
+}
@@ -0,0 +1,239 @@
+use anyhow::Result;
+use chrono::Utc;
+use clap::ValueEnum;
+use std::collections::HashMap;
+
+use super::history::TranscriptMessage;
+use crate::models::MessageResponse;
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum ExportFormat {
+    Jsonl,
+    Markdown,
+    Html,
+}
+
+/// 按指定格式渲染会话记录；消息须按时间升序排列
+pub fn render(
+    conversation_id: &str,
+    messages: &[MessageResponse],
+    format: ExportFormat,
+) -> Result<String> {
+    if format == ExportFormat::Jsonl {
+        let mut out = String::new();
+        for message in messages {
+            out.push_str(&serde_json::to_string(message)?);
+            out.push('\n');
+        }
+        return Ok(out);
+    }
+
+    let transcript = messages
+        .iter()
+        .map(TranscriptMessage::from_message)
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(match format {
+        ExportFormat::Markdown => render_markdown(conversation_id, &transcript),
+        _ => render_html(conversation_id, &transcript),
+    })
+}
+
+fn render_markdown(conversation_id: &str, messages: &[TranscriptMessage]) -> String {
+    let by_id: HashMap<&str, &TranscriptMessage> = messages
+        .iter()
+        .map(|message| (message.id.as_str(), message))
+        .collect();
+
+    let mut out = String::new();
+    out.push_str(&format!("# Conversation {}\n\n", conversation_id));
+    out.push_str(&format!(
+        "_Exported {} · {} message(s)_\n\n",
+        Utc::now().format("%Y-%m-%d %H:%M UTC"),
+        messages.len()
+    ));
+
+    for message in messages {
+        out.push_str(&format!("<a id=\"msg-{}\"></a>\n", message.id));
+        out.push_str(&format!(
+            "**{}** · {}",
+            message.sender,
+            format_time(message)
+        ));
+        if let Some(kind) = message.kind.as_deref().filter(|kind| *kind != "text") {
+            out.push_str(&format!(" · _{}_", kind));
+        }
+        out.push('\n');
+
+        if let Some(reply_to) = &message.reply_to {
+            out.push_str(&format!(
+                "> ↪ in reply to {}\n",
+                reply_reference(reply_to, &by_id, true)
+            ));
+        }
+        out.push('\n');
+
+        for line in message.content.lines() {
+            out.push_str(line);
+            out.push_str("  \n");
+        }
+        for url in &message.attachments {
+            out.push_str(&format!("📎 <{}>  \n", url));
+        }
+        out.push_str(&format!("\n<sub>id: {}</sub>\n\n---\n\n", message.id));
+    }
+
+    out
+}
+
+fn render_html(conversation_id: &str, messages: &[TranscriptMessage]) -> String {
+    let by_id: HashMap<&str, &TranscriptMessage> = messages
+        .iter()
+        .map(|message| (message.id.as_str(), message))
+        .collect();
+
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str(&format!(
+        "<title>Conversation {}</title>\n",
+        escape_html(conversation_id)
+    ));
+    out.push_str(
+        "<style>body{font-family:sans-serif;max-width:760px;margin:2em auto}\
+         .msg{border-bottom:1px solid #ddd;padding:.6em 0}\
+         .meta{color:#666;font-size:.9em}.reply{color:#666;border-left:3px solid #ccc;padding-left:.5em}\
+         .body{white-space:pre-wrap}</style>\n",
+    );
+    out.push_str("</head>\n<body>\n");
+    out.push_str(&format!(
+        "<h1>Conversation {}</h1>\n<p class=\"meta\">Exported {} · {} message(s)</p>\n",
+        escape_html(conversation_id),
+        Utc::now().format("%Y-%m-%d %H:%M UTC"),
+        messages.len()
+    ));
+
+    for message in messages {
+        out.push_str(&format!(
+            "<div class=\"msg\" id=\"msg-{}\">\n",
+            escape_html(&message.id)
+        ));
+        out.push_str(&format!(
+            "<div class=\"meta\"><strong>{}</strong> · {}</div>\n",
+            escape_html(&message.sender),
+            format_time(message)
+        ));
+        if let Some(reply_to) = &message.reply_to {
+            out.push_str(&format!(
+                "<div class=\"reply\">↪ in reply to {}</div>\n",
+                reply_reference(reply_to, &by_id, false)
+            ));
+        }
+        out.push_str(&format!(
+            "<div class=\"body\">{}</div>\n",
+            escape_html(&message.content)
+        ));
+        for url in &message.attachments {
+            out.push_str(&format!(
+                "<div>📎 <a href=\"{0}\">{0}</a></div>\n",
+                escape_html(url)
+            ));
+        }
+        out.push_str("</div>\n");
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+/// 生成指向被回复消息的链接；被回复消息不在导出范围内时只显示 ID
+fn reply_reference(
+    reply_to: &str,
+    by_id: &HashMap<&str, &TranscriptMessage>,
+    markdown: bool,
+) -> String {
+    match by_id.get(reply_to) {
+        Some(parent) => {
+            let excerpt = excerpt(&parent.content, 60);
+            if markdown {
+                format!("[{}: {}](#msg-{})", parent.sender, excerpt, reply_to)
+            } else {
+                format!(
+                    "<a href=\"#msg-{}\">{}: {}</a>",
+                    escape_html(reply_to),
+                    escape_html(&parent.sender),
+                    escape_html(&excerpt)
+                )
+            }
+        }
+        None if markdown => format!("`{}`", reply_to),
+        None => format!("<code>{}</code>", escape_html(reply_to)),
+    }
+}
+
+fn format_time(message: &TranscriptMessage) -> String {
+    message
+        .created_at
+        .map(|time| time.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn excerpt(content: &str, max_chars: usize) -> String {
+    let collapsed = content.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= max_chars {
+        collapsed
+    } else {
+        format!(
+            "{}...",
+            collapsed.chars().take(max_chars).collect::<String>()
+        )
+    }
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(id: &str, sender: &str, content: &str, reply_to: Option<&str>) -> TranscriptMessage {
+        TranscriptMessage {
+            id: id.to_string(),
+            sender: sender.to_string(),
+            created_at: None,
+            content: content.to_string(),
+            reply_to: reply_to.map(str::to_string),
+            kind: None,
+            attachments: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_markdown_links_replies_to_their_parent() {
+        let transcript = vec![
+            message("m1", "client", "Can you ship today?", None),
+            message("m2", "agent", "Yes", Some("m1")),
+            message("m3", "agent", "Follow-up", Some("gone")),
+        ];
+
+        let markdown = render_markdown("c1", &transcript);
+        assert!(markdown.contains("<a id=\"msg-m1\"></a>"));
+        assert!(markdown.contains("[client: Can you ship today?](#msg-m1)"));
+        assert!(markdown.contains("in reply to `gone`"));
+    }
+
+    #[test]
+    fn test_html_escapes_message_content() {
+        let transcript = vec![message("m1", "<b>x</b>", "<script>alert(1)</script>", None)];
+
+        let html = render_html("c1", &transcript);
+        assert!(html.contains("&lt;script&gt;"));
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("&lt;b&gt;x&lt;/b&gt;"));
+    }
+}
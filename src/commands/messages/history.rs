@@ -0,0 +1,199 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::api::ApiClient;
+use crate::models::{ConversationResponse, MessageResponse};
+use agentlink_protocol::message::MessageQuery;
+
+/// 每次请求拉取的消息条数
+pub const HISTORY_PAGE_SIZE: usize = 50;
+
+/// 构建消息查询参数：`before` 之前（不含）最多 `limit` 条
+pub fn message_query(before: Option<Uuid>, limit: usize) -> Result<MessageQuery> {
+    Ok(MessageQuery {
+        before,
+        limit: Some(limit.try_into()?),
+    })
+}
+
+/// 向前翻页拉取消息，直到取满 `max_messages` 或没有更早的消息；结果按时间升序排列
+pub async fn fetch_history(
+    client: &ApiClient,
+    conversation_id: &str,
+    before: Option<Uuid>,
+    max_messages: Option<usize>,
+) -> Result<Vec<MessageResponse>> {
+    let mut messages: Vec<MessageResponse> = Vec::new();
+    let mut seen = HashSet::new();
+    let mut cursor = before;
+
+    loop {
+        let remaining = max_messages.map(|max| max.saturating_sub(messages.len()));
+        if remaining == Some(0) {
+            break;
+        }
+        let page_size = remaining.map_or(HISTORY_PAGE_SIZE, |remaining| {
+            remaining.min(HISTORY_PAGE_SIZE)
+        });
+
+        let batch = client
+            .get_messages(conversation_id, message_query(cursor, page_size)?)
+            .await?;
+        let batch_len = batch.len();
+
+        // 以本页最早的一条作为下一页的游标
+        let oldest = batch
+            .iter()
+            .min_by_key(|message| message.created_at)
+            .map(|message| message.id);
+
+        let mut added = 0;
+        for message in batch {
+            if seen.insert(message.id) {
+                messages.push(message);
+                added += 1;
+            }
+        }
+
+        if batch_len < page_size || added == 0 || oldest.is_none() || oldest == cursor {
+            break;
+        }
+        cursor = oldest;
+    }
+
+    messages.sort_by_key(|message| message.created_at);
+    if let Some(max) = max_messages {
+        // 超出部分来自最早的消息
+        let excess = messages.len().saturating_sub(max);
+        messages.drain(..excess);
+    }
+    Ok(messages)
+}
+
+/// 当前 agent 的身份，用于识别自己发出的消息
+#[derive(Debug, Clone)]
+pub struct SelfIdentity {
+    pub id: Uuid,
+    pub linkid: String,
+}
+
+impl SelfIdentity {
+    pub async fn fetch(client: &ApiClient) -> Result<Self> {
+        let user = client.verify_agent_identity().await?;
+        Ok(Self {
+            id: user.id,
+            linkid: user.linkid,
+        })
+    }
+
+    /// 判断消息是否由自己发出
+    pub fn sent(&self, message: &MessageResponse) -> bool {
+        message.sender_id == self.id
+    }
+}
+
+/// 导出与展示用的消息视图
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranscriptMessage {
+    pub id: String,
+    pub sender: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub content: String,
+    pub reply_to: Option<String>,
+    pub kind: Option<String>,
+    pub attachments: Vec<String>,
+}
+
+impl TranscriptMessage {
+    pub fn from_message(message: &MessageResponse) -> Result<Self> {
+        // 消息类型按协议中的序列化名称展示
+        let kind = serde_json::to_value(&message.kind)?
+            .as_str()
+            .map(str::to_string);
+
+        Ok(Self {
+            id: message.id.to_string(),
+            sender: message.sender_name.clone(),
+            created_at: Some(message.created_at),
+            content: message.content.clone(),
+            reply_to: message.reply_to.map(|id| id.to_string()),
+            kind,
+            attachments: attachment_urls(message.metadata.as_ref()),
+        })
+    }
+}
+
+/// metadata 中 `attachments` 的一项，只关心链接
+#[derive(Deserialize)]
+struct AttachmentLink {
+    url: String,
+}
+
+/// 读取 metadata 中 `attachments` 的链接；不符合附件结构的条目会被忽略
+fn attachment_urls(metadata: Option<&Value>) -> Vec<String> {
+    let Some(Value::Array(attachments)) = metadata.and_then(|metadata| metadata.get("attachments"))
+    else {
+        return Vec::new();
+    };
+
+    attachments
+        .iter()
+        .filter_map(|attachment| serde_json::from_value::<AttachmentLink>(attachment.clone()).ok())
+        .map(|attachment| attachment.url)
+        .collect()
+}
+
+/// 会话标题；没有标题时用参与者 linkid 拼接
+pub fn conversation_label(conversation: &ConversationResponse) -> String {
+    if let Some(title) = conversation
+        .title
+        .as_deref()
+        .filter(|title| !title.is_empty())
+    {
+        return title.to_string();
+    }
+
+    conversation
+        .participants
+        .iter()
+        .map(|participant| participant.linkid.clone())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_attachment_urls_reads_typed_attachments() {
+        let metadata = json!({
+            "status": "delivered",
+            "attachments": [
+                {"url": "https://cdn.example.com/a.pdf", "content_type": "application/pdf"},
+                {"name": "not an attachment"},
+                {"url": "https://cdn.example.com/b.png"}
+            ]
+        });
+
+        assert_eq!(
+            attachment_urls(Some(&metadata)),
+            vec![
+                "https://cdn.example.com/a.pdf",
+                "https://cdn.example.com/b.png"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_attachment_urls_tolerates_missing_metadata() {
+        assert!(attachment_urls(None).is_empty());
+        assert!(attachment_urls(Some(&json!({"attachments": "x"}))).is_empty());
+        assert!(attachment_urls(Some(&json!([1, 2]))).is_empty());
+    }
+}
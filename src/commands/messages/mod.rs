@@ -0,0 +1,572 @@
+use anyhow::{Context, Result};
+use clap::{Subcommand, ValueEnum};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+use crate::api::ApiClient;
+use crate::commands::queue::{self, QueuedPayload};
+use crate::config::Config;
+use crate::models::{ConversationResponse, ConversationType, MessageType, ParticipantResponse};
+use crate::utils::input::{read_text, TextSource};
+use crate::utils::output::{print_error, print_success, print_table, print_warning};
+use crate::utils::time::{parse_datetime, parse_interval};
+
+pub mod autorespond;
+pub mod bridge;
+pub mod chat;
+pub mod compose;
+pub mod export;
+pub mod history;
+pub mod resolve;
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub(crate) enum ConversationKindArg {
+    Direct,
+    Group,
+}
+
+impl From<ConversationKindArg> for ConversationType {
+    fn from(value: ConversationKindArg) -> Self {
+        match value {
+            ConversationKindArg::Direct => ConversationType::Direct,
+            ConversationKindArg::Group => ConversationType::Group,
+        }
+    }
+}
+
+/// 消息类型，与协议 `MessageType` 一一对应
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub(crate) enum MessageKindArg {
+    Text,
+    Image,
+    File,
+    System,
+}
+
+impl From<MessageKindArg> for MessageType {
+    fn from(value: MessageKindArg) -> Self {
+        match value {
+            MessageKindArg::Text => MessageType::Text,
+            MessageKindArg::Image => MessageType::Image,
+            MessageKindArg::File => MessageType::File,
+            MessageKindArg::System => MessageType::System,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+pub enum MessageCommands {
+    /// 列出所有会话
+    List,
+
+    /// 查看会话消息
+    Show {
+        /// 会话 ID，或 @linkid（与该用户的单聊）
+        conversation_id: String,
+
+        /// 只显示该消息 ID 之前的消息
+        #[arg(long)]
+        before: Option<Uuid>,
+
+        /// 显示的消息条数
+        #[arg(long, default_value = "50")]
+        limit: usize,
+
+        /// 向前翻页拉取全部历史消息
+        #[arg(long, conflicts_with = "limit")]
+        all: bool,
+    },
+
+    /// 导出完整会话记录（含回复关系）
+    Export {
+        /// 会话 ID，或 @linkid（与该用户的单聊）
+        conversation_id: String,
+
+        /// 导出格式
+        #[arg(long = "format", value_enum, default_value = "markdown")]
+        export_format: export::ExportFormat,
+
+        /// 输出文件路径，省略时输出到标准输出
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+    },
+
+    /// 发送消息
+    Send {
+        /// 会话 ID，或 @linkid（与该用户的单聊，不存在时自动创建）
+        conversation_id: String,
+
+        /// 消息内容；传 `-` 从标准输入读取
+        message: Option<String>,
+
+        /// 从文件读取消息内容
+        #[arg(long, conflicts_with = "message")]
+        file: Option<PathBuf>,
+
+        /// 回复指定消息 ID
+        #[arg(long)]
+        reply_to: Option<String>,
+
+        /// 消息类型（默认 text）
+        #[arg(long, value_enum)]
+        kind: Option<MessageKindArg>,
+
+        /// 附加的结构化元数据：JSON 字符串，或 @path 从文件读取
+        #[arg(long, value_parser = compose::parse_metadata)]
+        metadata: Option<serde_json::Value>,
+
+        /// 定时发送：加入本地发送队列（例如 "2026-11-01 09:00"、RFC 3339 或 +2h）
+        #[arg(long, value_parser = parse_datetime)]
+        at: Option<DateTime<Utc>>,
+    },
+
+    /// 创建新会话
+    Create {
+        /// 会话类型
+        #[arg(long, value_enum, default_value = "direct")]
+        kind: ConversationKindArg,
+
+        /// 群聊标题，仅 group 有意义
+        #[arg(long)]
+        title: Option<String>,
+
+        /// 参与者用户 ID 或 @linkid（多个用逗号分隔）
+        #[arg(short, long)]
+        participants: String,
+    },
+
+    /// 进入会话的交互式聊天
+    Chat {
+        /// 会话 ID，或 @linkid（与该用户的单聊）
+        conversation_id: String,
+
+        /// 进入时显示的历史消息条数
+        #[arg(long, default_value = "20")]
+        history: usize,
+
+        /// 拉取新消息的间隔（例如 3s、1m）
+        #[arg(long, default_value = "3s", value_parser = parse_interval)]
+        interval: std::time::Duration,
+    },
+
+    /// 按规则文件自动回复新消息
+    Autorespond {
+        /// YAML 规则文件
+        #[arg(long)]
+        rules: PathBuf,
+
+        /// 检查新消息的间隔（例如 30s、1m）
+        #[arg(long, default_value = "30s", value_parser = parse_interval)]
+        interval: std::time::Duration,
+
+        /// 只检查一次后退出，适合放在 cron 中
+        #[arg(long)]
+        once: bool,
+
+        /// 只记录将要发送的回复，不实际发送
+        #[arg(long)]
+        dry_run: bool,
+
+        /// 以 JSON Lines 追加记录每次匹配与发送结果
+        #[arg(long)]
+        log: Option<PathBuf>,
+    },
+
+    /// 把会话桥接到外部进程：新消息以 JSON 行写入其标准输入，其输出的 JSON 行作为消息发送
+    Bridge {
+        /// 会话 ID，或 @linkid（与该用户的单聊）
+        conversation_id: String,
+
+        /// 拉取新消息的间隔（例如 3s、1m）
+        #[arg(long, default_value = "3s", value_parser = parse_interval)]
+        interval: std::time::Duration,
+
+        /// 等待写入子进程的消息上限，达到后暂停拉取
+        #[arg(long, default_value = "100")]
+        queue: usize,
+
+        /// 子进程连续异常退出的最大重启次数
+        #[arg(long, default_value = "5")]
+        max_restarts: u32,
+
+        /// 子进程以 0 退出时结束桥接（默认总是重启）
+        #[arg(long)]
+        exit_on_success: bool,
+
+        /// 子进程命令及参数，写在 `--` 之后
+        #[arg(last = true, required = true)]
+        command: Vec<String>,
+    },
+
+    /// 实时监听消息（WebSocket）
+    Watch { conversation_id: Option<String> },
+}
+
+pub async fn execute(
+    command: MessageCommands,
+    config: &Config,
+    format: crate::OutputFormat,
+) -> Result<()> {
+    ensure_authenticated(config)?;
+    let client = ApiClient::new(config)?;
+
+    match command {
+        MessageCommands::List => match client
+            .list_conversations(agentlink_protocol::message::ConversationQuery {
+                page: None,
+                per_page: None,
+            })
+            .await
+        {
+            Ok(conversations) => {
+                if conversations.is_empty() {
+                    println!("{}", "No conversations found.".yellow());
+                    return Ok(());
+                }
+
+                match format {
+                    crate::OutputFormat::Json => {
+                        println!("{}", serde_json::to_string_pretty(&conversations)?);
+                    }
+                    crate::OutputFormat::Yaml => {
+                        println!("{}", serde_yaml::to_string(&conversations)?);
+                    }
+                    _ => print_conversations(&conversations),
+                }
+                Ok(())
+            }
+            Err(error) => {
+                print_error(&format!("Failed to list conversations: {}", error));
+                Ok(())
+            }
+        },
+        MessageCommands::Show {
+            conversation_id,
+            before,
+            limit,
+            all,
+        } => {
+            let conversation_id =
+                resolve::resolve_conversation(&client, &conversation_id, false).await?;
+            let max_messages = if all { None } else { Some(limit) };
+            match history::fetch_history(&client, &conversation_id, before, max_messages).await {
+                Ok(messages) => {
+                    if messages.is_empty() {
+                        println!("{}", "No messages in this conversation.".yellow());
+                        return Ok(());
+                    }
+
+                    match format {
+                        crate::OutputFormat::Json => {
+                            println!("{}", serde_json::to_string_pretty(&messages)?);
+                        }
+                        crate::OutputFormat::Yaml => {
+                            println!("{}", serde_yaml::to_string(&messages)?);
+                        }
+                        _ => {
+                            println!("\n{}:\n", "Messages".bold().underline());
+                            for message in &messages {
+                                let sender = message.sender_name.cyan();
+                                let time = message
+                                    .created_at
+                                    .format("%m-%d %H:%M")
+                                    .to_string()
+                                    .dimmed();
+                                println!("{} {}: {}", sender, time, message.content);
+                            }
+                            if let Some(oldest) = messages.first().filter(|_| !all && messages.len() >= limit) {
+                                println!(
+                                    "\n{}",
+                                    format!(
+                                        "Older messages: agentlink messages show {} --before {}",
+                                        conversation_id, oldest.id
+                                    )
+                                    .dimmed()
+                                );
+                            }
+                        }
+                    }
+                    Ok(())
+                }
+                Err(error) => {
+                    print_error(&format!("Failed to get messages: {}", error));
+                    Ok(())
+                }
+            }
+        }
+        MessageCommands::Export {
+            conversation_id,
+            export_format,
+            out,
+        } => {
+            let conversation_id =
+                resolve::resolve_conversation(&client, &conversation_id, false).await?;
+            let messages =
+                match history::fetch_history(&client, &conversation_id, None, None).await {
+                    Ok(messages) => messages,
+                    Err(error) => {
+                        print_error(&format!("Failed to get messages: {}", error));
+                        return Ok(());
+                    }
+                };
+
+            let rendered = export::render(&conversation_id, &messages, export_format)?;
+            match out {
+                Some(path) => {
+                    std::fs::write(&path, rendered)
+                        .with_context(|| format!("Failed to write export file: {:?}", path))?;
+                    print_success(&format!(
+                        "Exported {} message(s) to {}",
+                        messages.len(),
+                        path.display()
+                    ));
+                }
+                None => print!("{}", rendered),
+            }
+            Ok(())
+        }
+        MessageCommands::Send {
+            conversation_id,
+            message,
+            file,
+            reply_to,
+            kind,
+            metadata,
+            at,
+        } => {
+            let content = read_text(
+                TextSource {
+                    inline: message,
+                    file: file.as_deref(),
+                    edit: false,
+                    extension: ".md",
+                },
+                "message",
+            )?;
+            let body = compose::MessageDraft {
+                content,
+                kind: kind.map(MessageType::from),
+                reply_to,
+                metadata,
+            }
+            .into_request()?;
+
+            // 定时发送时 @linkid 留到发送时再解析，届时才创建单聊
+            if let Some(at) = at {
+                if at <= Utc::now() {
+                    print_warning(
+                        "The scheduled time is in the past; the message will be sent on the next queue run.",
+                    );
+                }
+
+                let item = queue::enqueue(
+                    config,
+                    QueuedPayload::Message {
+                        conversation_id: conversation_id.trim().to_string(),
+                        request: body,
+                    },
+                    at,
+                )
+                .await?;
+                queue::print_enqueued(&item);
+                return Ok(());
+            }
+
+            let conversation_id =
+                resolve::resolve_conversation(&client, &conversation_id, true).await?;
+
+            match client.send_message(&conversation_id, body).await {
+                Ok(message) => {
+                    print_success("Message sent.");
+                    println!("{}: {}", "ID".bold(), message.id);
+                    println!(
+                        "{}: {}",
+                        "Sent At".bold(),
+                        message.created_at.format("%Y-%m-%d %H:%M:%S")
+                    );
+                    Ok(())
+                }
+                Err(error) => {
+                    print_error(&format!("Failed to send message: {}", error));
+                    Ok(())
+                }
+            }
+        }
+        MessageCommands::Create {
+            kind,
+            title,
+            participants,
+        } => {
+            let mut participant_ids = Vec::new();
+            for participant in participants
+                .split(',')
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+            {
+                participant_ids.push(client.resolve_user_id(participant).await?);
+            }
+
+            let conversation = client
+                .create_conversation(crate::models::CreateConversationRequest {
+                    kind: kind.into(),
+                    title,
+                    participant_ids,
+                })
+                .await;
+
+            match conversation {
+                Ok(conversation) => {
+                    print_success("Conversation created.");
+                    println!("{}: {}", "ID".bold(), conversation.id);
+                    Ok(())
+                }
+                Err(error) => {
+                    print_error(&format!("Failed to create conversation: {}", error));
+                    Ok(())
+                }
+            }
+        }
+        MessageCommands::Chat {
+            conversation_id,
+            history,
+            interval,
+        } => {
+            let conversation_id =
+                resolve::resolve_conversation(&client, &conversation_id, false).await?;
+            chat::run(
+                &client,
+                chat::ChatOptions {
+                    conversation_id,
+                    history,
+                    interval,
+                },
+            )
+            .await
+        }
+        MessageCommands::Autorespond {
+            rules,
+            interval,
+            once,
+            dry_run,
+            log,
+        } => {
+            autorespond::run(
+                &client,
+                config,
+                autorespond::AutorespondOptions {
+                    rules,
+                    interval,
+                    once,
+                    dry_run,
+                    log,
+                },
+            )
+            .await
+        }
+        MessageCommands::Bridge {
+            conversation_id,
+            interval,
+            queue,
+            max_restarts,
+            exit_on_success,
+            command,
+        } => {
+            let conversation_id =
+                resolve::resolve_conversation(&client, &conversation_id, false).await?;
+            bridge::run(
+                &client,
+                bridge::BridgeOptions {
+                    conversation_id,
+                    command,
+                    interval,
+                    queue,
+                    max_restarts,
+                    exit_on_success,
+                },
+            )
+            .await
+        }
+        MessageCommands::Watch { conversation_id } => {
+            println!("{}", "Starting message watcher...".cyan());
+            println!("Press Ctrl+C to exit.\n");
+
+            if let Some(id) = conversation_id {
+                println!("Watching conversation: {}", id);
+            } else {
+                println!("Watching all conversations");
+            }
+
+            println!("\n{}", "WebSocket support coming soon...".yellow());
+            Ok(())
+        }
+    }
+}
+
+fn ensure_authenticated(config: &Config) -> Result<()> {
+    if config.has_api_key() {
+        Ok(())
+    } else {
+        anyhow::bail!(
+            "No agent API key configured. Run `agentlink api-key set <sk_...>` or pass `--api-key`."
+        )
+    }
+}
+
+fn print_conversations(conversations: &[ConversationResponse]) {
+    println!("\n{}:\n", "Conversations".bold().underline());
+
+    let data: Vec<Vec<String>> = conversations
+        .iter()
+        .map(|conversation| {
+            let last_message = conversation
+                .last_message
+                .as_ref()
+                .map(|message| {
+                    if message.content.chars().count() > 50 {
+                        format!(
+                            "{}...",
+                            message.content.chars().take(50).collect::<String>()
+                        )
+                    } else {
+                        message.content.clone()
+                    }
+                })
+                .unwrap_or_else(|| "No messages".to_string());
+
+            vec![
+                conversation.id.to_string(),
+                format_participants(&conversation.participants),
+                last_message,
+                conversation.unread_count.to_string(),
+                conversation
+                    .last_message_at
+                    .unwrap_or(conversation.created_at)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string(),
+            ]
+        })
+        .collect();
+
+    print_table(
+        vec!["ID", "Participants", "Last Message", "Unread", "Updated"],
+        data,
+    );
+}
+
+fn format_participants(participants: &[ParticipantResponse]) -> String {
+    if participants.len() <= 2 {
+        participants
+            .iter()
+            .map(|participant| participant.linkid.clone())
+            .collect::<Vec<_>>()
+            .join(", ")
+    } else {
+        format!(
+            "{} and {} others",
+            participants[0].linkid,
+            participants.len() - 1
+        )
+    }
+}
@@ -0,0 +1,104 @@
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+use crate::api::ApiClient;
+use crate::models::{ConversationResponse, ConversationType, CreateConversationRequest};
+use crate::utils::output::print_success;
+
+/// 将会话参数解析为会话 ID
+///
+/// `@linkid` 表示与该用户的单聊；`create` 为真时不存在则自动创建，其余输入原样作为会话 ID
+pub async fn resolve_conversation(
+    client: &ApiClient,
+    target: &str,
+    create: bool,
+) -> Result<String> {
+    let target = target.trim();
+    let Some(linkid) = target.strip_prefix('@') else {
+        return Ok(target.to_string());
+    };
+
+    let user_id = client.resolve_user_id(linkid).await?;
+    // 翻完所有会话再决定是否创建，避免较早的单聊不在第一页时重复创建
+    let conversations = client
+        .list_all_conversations()
+        .await
+        .context("Failed to list conversations")?;
+
+    if let Some(conversation) = conversations.iter().find(|conversation| {
+        is_direct_with(
+            &conversation.kind,
+            conversation
+                .participants
+                .iter()
+                .map(|participant| participant.user_id),
+            user_id,
+        )
+    }) {
+        return Ok(conversation.id.to_string());
+    }
+
+    if !create {
+        anyhow::bail!(
+            "No direct conversation with @{}. Send a message with `agentlink messages send @{} ...` to start one.",
+            linkid,
+            linkid
+        );
+    }
+
+    let conversation: ConversationResponse = client
+        .create_conversation(CreateConversationRequest {
+            kind: ConversationType::Direct,
+            title: None,
+            participant_ids: vec![user_id],
+        })
+        .await
+        .with_context(|| format!("Failed to create a conversation with @{}", linkid))?;
+    print_success(&format!(
+        "Started a direct conversation with @{} ({}).",
+        linkid, conversation.id
+    ));
+    Ok(conversation.id.to_string())
+}
+
+/// 判断会话是否为与指定用户的单聊
+fn is_direct_with(
+    kind: &ConversationType,
+    mut participant_ids: impl Iterator<Item = Uuid>,
+    user_id: Uuid,
+) -> bool {
+    matches!(kind, ConversationType::Direct) && participant_ids.any(|id| id == user_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_direct_with_matches_participant_user_id() {
+        let me = Uuid::new_v4();
+        let alice = Uuid::new_v4();
+        let participants = [me, alice];
+
+        assert!(is_direct_with(
+            &ConversationType::Direct,
+            participants.into_iter(),
+            alice
+        ));
+        assert!(!is_direct_with(
+            &ConversationType::Direct,
+            participants.into_iter(),
+            Uuid::new_v4()
+        ));
+    }
+
+    #[test]
+    fn test_is_direct_with_skips_group_conversations() {
+        let alice = Uuid::new_v4();
+        assert!(!is_direct_with(
+            &ConversationType::Group,
+            [Uuid::new_v4(), alice].into_iter(),
+            alice
+        ));
+    }
+}
@@ -2,8 +2,13 @@ pub mod agent;
 pub mod api_key;
 pub mod config;
 pub mod feed;
+pub mod inbox;
+pub mod index;
 pub mod messages;
 pub mod notifications;
 pub mod posts;
+pub mod queue;
+pub mod search;
+pub mod skills;
 pub mod tasks;
 pub mod update;
@@ -0,0 +1,236 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::NotificationKindArg;
+use crate::api::ApiClient;
+use agentlink_protocol::message::{NotificationResponse, NotificationType};
+
+/// `--all` 翻页时的安全上限，防止服务端分页异常导致死循环
+const MAX_PAGES: i64 = 1000;
+
+/// 通知筛选条件；服务端不支持这些条件，在拉取到的通知上本地筛选
+#[derive(Debug, Default, Clone)]
+pub struct NotificationFilter {
+    /// 通知类型，任一匹配即可；为空表示不限
+    pub kinds: Vec<NotificationKindArg>,
+    pub since: Option<DateTime<Utc>>,
+    pub before: Option<DateTime<Utc>>,
+}
+
+impl NotificationFilter {
+    pub fn is_empty(&self) -> bool {
+        self.kinds.is_empty() && self.since.is_none() && self.before.is_none()
+    }
+
+    pub fn matches(&self, notification: &NotificationResponse) -> bool {
+        self.matches_parts(&notification.kind, notification.created_at)
+    }
+
+    fn matches_parts(&self, kind: &NotificationType, created_at: DateTime<Utc>) -> bool {
+        (self.kinds.is_empty()
+            || self
+                .kinds
+                .iter()
+                .any(|wanted| NotificationType::from(*wanted) == *kind))
+            && self.since.map_or(true, |since| created_at >= since)
+            && self.before.map_or(true, |before| created_at < before)
+    }
+}
+
+/// 通知类型的展示名，与 `--kind` 的取值一致（例如 `task_application`）
+pub fn kind_name(notification: &NotificationResponse) -> String {
+    NotificationKindArg::from(&notification.kind).name()
+}
+
+/// 拉取一页通知
+pub async fn fetch_page(
+    client: &ApiClient,
+    unread_only: bool,
+    page: i64,
+    per_page: i64,
+) -> Result<Vec<NotificationResponse>> {
+    client
+        .list_notifications_page(unread_only, Some(page), Some(per_page))
+        .await
+}
+
+/// 逐页拉取全部通知，直到某一页不满
+pub async fn fetch_all(
+    client: &ApiClient,
+    unread_only: bool,
+    per_page: i64,
+) -> Result<Vec<NotificationResponse>> {
+    let per_page = per_page.max(1);
+    let mut notifications = Vec::new();
+    for page in 1..=MAX_PAGES {
+        let batch = fetch_page(client, unread_only, page, per_page).await?;
+        let done = (batch.len() as i64) < per_page;
+        notifications.extend(batch);
+        if done {
+            break;
+        }
+    }
+    Ok(notifications)
+}
+
+/// 通知关联的实体
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkedEntity {
+    Task(String),
+    Post(String),
+    Conversation(String),
+    /// 评论 ID；通知中没有所属动态的 ID，无法直接打开
+    Comment(String),
+}
+
+/// 通知关联的任务、动态或会话，来自 `related_type` 和 `related_id`
+pub fn linked_entity(notification: &NotificationResponse) -> Option<LinkedEntity> {
+    related_entity(
+        notification.related_type.as_deref(),
+        notification.related_id,
+    )
+}
+
+fn related_entity(related_type: Option<&str>, related_id: Option<Uuid>) -> Option<LinkedEntity> {
+    let id = related_id?.to_string();
+    match related_type?.trim().to_ascii_lowercase().as_str() {
+        "task" | "tasks" => Some(LinkedEntity::Task(id)),
+        "post" | "posts" => Some(LinkedEntity::Post(id)),
+        "comment" | "comments" => Some(LinkedEntity::Comment(id)),
+        "conversation" | "conversations" | "message" | "messages" => {
+            Some(LinkedEntity::Conversation(id))
+        }
+        _ => None,
+    }
+}
+
+fn id_field(scope: &Value, key: &str) -> Option<String> {
+    match scope.get(key)? {
+        Value::String(id) if !id.is_empty() => Some(id.clone()),
+        Value::Number(id) => Some(id.to_string()),
+        _ => None,
+    }
+}
+
+/// 解析待处理的通知 ID：每行一个 ID、`notifications list` 的 JSON 数组，或每行一个 JSON 对象
+pub fn parse_ids(text: &str) -> Result<Vec<String>> {
+    let text = text.trim();
+    let mut ids: Vec<String> = Vec::new();
+
+    if text.starts_with('[') {
+        let values: Vec<Value> = serde_json::from_str(text)?;
+        for value in &values {
+            ids.push(id_from_value(value)?);
+        }
+    } else {
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with('{') {
+                ids.push(id_from_value(&serde_json::from_str(line)?)?);
+            } else {
+                ids.extend(
+                    line.split([',', ' ', '\t'])
+                        .filter(|id| !id.is_empty())
+                        .map(str::to_string),
+                );
+            }
+        }
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    ids.retain(|id| seen.insert(id.clone()));
+    Ok(ids)
+}
+
+fn id_from_value(value: &Value) -> Result<String> {
+    match value {
+        Value::String(id) => Ok(id.clone()),
+        Value::Object(_) => id_field(value, "id")
+            .ok_or_else(|| anyhow::anyhow!("Notification entry has no `id`: {}", value)),
+        other => anyhow::bail!("Unsupported notification ID entry: {}", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use clap::ValueEnum;
+
+    #[test]
+    fn test_filter_matches_kind_and_time_range() {
+        let filter = NotificationFilter {
+            kinds: vec![NotificationKindArg::TaskApplication],
+            since: Some(Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap()),
+            before: Some(Utc.with_ymd_and_hms(2026, 3, 10, 0, 0, 0).unwrap()),
+        };
+        let inside = Utc.with_ymd_and_hms(2026, 3, 5, 0, 0, 0).unwrap();
+        let after = Utc.with_ymd_and_hms(2026, 3, 10, 0, 0, 0).unwrap();
+
+        assert!(filter.matches_parts(&NotificationType::TaskApplication, inside));
+        assert!(!filter.matches_parts(&NotificationType::TaskApplication, after));
+        assert!(!filter.matches_parts(&NotificationType::NewMessage, inside));
+        assert!(NotificationFilter::default().matches_parts(&NotificationType::System, after));
+    }
+
+    #[test]
+    fn test_kind_names_round_trip_through_kind_argument() {
+        assert_eq!(
+            NotificationKindArg::from(&NotificationType::TaskApplication).name(),
+            "task_application"
+        );
+        for kind in NotificationKindArg::value_variants() {
+            let parsed = NotificationKindArg::from_str(&kind.name(), false).unwrap();
+            assert_eq!(parsed, *kind);
+            assert_eq!(
+                NotificationKindArg::from(&NotificationType::from(*kind)),
+                *kind
+            );
+        }
+    }
+
+    #[test]
+    fn test_related_entity_maps_related_type() {
+        let id = Uuid::new_v4();
+        assert_eq!(
+            related_entity(Some("task"), Some(id)),
+            Some(LinkedEntity::Task(id.to_string()))
+        );
+        assert_eq!(
+            related_entity(Some("Post"), Some(id)),
+            Some(LinkedEntity::Post(id.to_string()))
+        );
+        assert_eq!(
+            related_entity(Some("conversation"), Some(id)),
+            Some(LinkedEntity::Conversation(id.to_string()))
+        );
+        assert_eq!(
+            related_entity(Some("comment"), Some(id)),
+            Some(LinkedEntity::Comment(id.to_string()))
+        );
+        assert_eq!(related_entity(Some("user"), Some(id)), None);
+        assert_eq!(related_entity(Some("task"), None), None);
+        assert_eq!(related_entity(None, Some(id)), None);
+    }
+
+    #[test]
+    fn test_parse_ids_accepts_lines_and_json() {
+        assert_eq!(
+            parse_ids("a\n\nb, c\n# note\na\n").unwrap(),
+            vec!["a", "b", "c"]
+        );
+        assert_eq!(
+            parse_ids(r#"[{"id": "n1", "title": "x"}, "n2"]"#).unwrap(),
+            vec!["n1", "n2"]
+        );
+        assert_eq!(
+            parse_ids("{\"id\": \"n1\"}\n{\"id\": \"n2\"}").unwrap(),
+            vec!["n1", "n2"]
+        );
+        assert!(parse_ids(r#"[{"title": "x"}]"#).is_err());
+    }
+}
@@ -0,0 +1,466 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use clap::{Subcommand, ValueEnum};
+use colored::Colorize;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::api::ApiClient;
+use crate::config::Config;
+use crate::utils::input::{read_text, TextSource};
+use crate::utils::output::{print_error, print_success, print_table, print_warning};
+use crate::utils::time::{parse_datetime, parse_interval};
+
+pub mod filter;
+pub mod route;
+
+use filter::{LinkedEntity, NotificationFilter};
+use agentlink_protocol::message::NotificationType;
+
+/// 通知类型，与协议 `NotificationType` 一一对应；路由配置的 `kinds` 使用相同的取值
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum, Deserialize)]
+#[value(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKindArg {
+    NewMessage,
+    TaskApplication,
+    PostComment,
+    System,
+}
+
+impl From<NotificationKindArg> for NotificationType {
+    fn from(value: NotificationKindArg) -> Self {
+        match value {
+            NotificationKindArg::NewMessage => NotificationType::NewMessage,
+            NotificationKindArg::TaskApplication => NotificationType::TaskApplication,
+            NotificationKindArg::PostComment => NotificationType::PostComment,
+            NotificationKindArg::System => NotificationType::System,
+        }
+    }
+}
+
+impl From<&NotificationType> for NotificationKindArg {
+    fn from(value: &NotificationType) -> Self {
+        match value {
+            NotificationType::NewMessage => NotificationKindArg::NewMessage,
+            NotificationType::TaskApplication => NotificationKindArg::TaskApplication,
+            NotificationType::PostComment => NotificationKindArg::PostComment,
+            NotificationType::System => NotificationKindArg::System,
+        }
+    }
+}
+
+impl NotificationKindArg {
+    /// 命令行中的取值，例如 `task_application`
+    pub fn name(self) -> String {
+        self.to_possible_value()
+            .map(|value| value.get_name().to_string())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Subcommand)]
+pub enum NotificationCommands {
+    /// 列出通知
+    List {
+        #[arg(short, long)]
+        unread: bool,
+
+        /// 只显示指定类型的通知，可重复指定；筛选时会翻页拉取全部通知
+        #[arg(long = "kind", value_enum)]
+        kinds: Vec<NotificationKindArg>,
+
+        /// 只显示该时间之后的通知（例如 2026-01-01、-24h）；筛选时会翻页拉取全部通知
+        #[arg(long, value_parser = parse_datetime)]
+        since: Option<DateTime<Utc>>,
+
+        #[arg(
+            short,
+            long,
+            default_value = "1",
+            conflicts_with_all = ["all", "kinds", "since"]
+        )]
+        page: i64,
+
+        #[arg(long = "per-page", default_value = "20")]
+        per_page: i64,
+
+        /// 翻页拉取全部通知
+        #[arg(long)]
+        all: bool,
+
+        /// 打开通知关联的任务、动态或会话
+        #[arg(long, value_name = "ID")]
+        open: Option<String>,
+    },
+
+    /// 标记通知为已读
+    MarkRead {
+        /// 通知 ID，不指定且没有筛选条件时标记全部已读
+        #[arg(conflicts_with_all = ["kinds", "before", "ids_from"])]
+        id: Option<String>,
+
+        /// 只标记指定类型的未读通知，可重复指定
+        #[arg(long = "kind", value_enum)]
+        kinds: Vec<NotificationKindArg>,
+
+        /// 只标记该时间之前的未读通知
+        #[arg(long, value_parser = parse_datetime)]
+        before: Option<DateTime<Utc>>,
+
+        /// 从文件读取通知 ID，`-` 表示标准输入；每行一个 ID，或 `notifications list` 的 JSON 输出
+        #[arg(long, value_name = "FILE", conflicts_with_all = ["kinds", "before"])]
+        ids_from: Option<String>,
+    },
+
+    /// 按规则文件把通知分发给命令、会话、文件或本机 webhook（长期运行）
+    Route {
+        /// YAML 路由配置文件
+        #[arg(long, value_name = "FILE")]
+        config: PathBuf,
+
+        /// 检查间隔（例如 30s、1m）
+        #[arg(long, default_value = "30s", value_parser = parse_interval)]
+        interval: Duration,
+
+        /// 只检查一次后退出（适合 cron）
+        #[arg(long)]
+        once: bool,
+
+        /// 仅打印将要执行的动作，不执行、不标记已读
+        #[arg(long)]
+        dry_run: bool,
+
+        /// 同时处理首次运行前已有的未读通知
+        #[arg(long)]
+        backlog: bool,
+    },
+
+    /// 实时监听通知
+    Watch,
+}
+
+pub async fn execute(
+    command: NotificationCommands,
+    config: &Config,
+    format: crate::OutputFormat,
+) -> Result<()> {
+    ensure_authenticated(config)?;
+    let client = ApiClient::new(config)?;
+
+    match command {
+        NotificationCommands::List {
+            open: Some(id),
+            per_page,
+            ..
+        } => open_linked(&client, &id, per_page, config, format).await,
+        NotificationCommands::List {
+            unread,
+            kinds,
+            since,
+            page,
+            per_page,
+            all,
+            open: None,
+        } => {
+            // 服务端只支持按已读状态分页，类型和时间筛选在全部通知上进行
+            let criteria = NotificationFilter {
+                kinds,
+                since,
+                before: None,
+            };
+            let all = all || !criteria.is_empty();
+            let result = if all {
+                filter::fetch_all(&client, unread, per_page).await
+            } else {
+                filter::fetch_page(&client, unread, page, per_page).await
+            };
+
+            match result {
+                Ok(notifications) => {
+                    let notifications: Vec<_> = notifications
+                        .into_iter()
+                        .filter(|notification| criteria.matches(notification))
+                        .collect();
+
+                    if notifications.is_empty() {
+                        if unread {
+                            println!("{}", "No unread notifications.".green());
+                        } else {
+                            println!("{}", "No notifications.".yellow());
+                        }
+                        return Ok(());
+                    }
+
+                    match format {
+                        crate::OutputFormat::Json => {
+                            println!("{}", serde_json::to_string_pretty(&notifications)?);
+                        }
+                        crate::OutputFormat::Yaml => {
+                            println!("{}", serde_yaml::to_string(&notifications)?);
+                        }
+                        _ => {
+                            let title = if unread {
+                                "Unread Notifications"
+                            } else {
+                                "Notifications"
+                            };
+                            if all {
+                                println!("\n{}:\n", title.bold().underline());
+                            } else {
+                                println!("\n{} (Page {}):\n", title.bold().underline(), page);
+                            }
+
+                            let data: Vec<Vec<String>> = notifications
+                                .iter()
+                                .map(|notification| {
+                                    let status = if notification.is_read {
+                                        "✓".green().to_string()
+                                    } else {
+                                        "○".yellow().to_string()
+                                    };
+
+                                    vec![
+                                        status,
+                                        notification.id.to_string(),
+                                        filter::kind_name(notification),
+                                        notification.title.clone(),
+                                        notification
+                                            .created_at
+                                            .format("%Y-%m-%d %H:%M")
+                                            .to_string(),
+                                    ]
+                                })
+                                .collect();
+
+                            print_table(vec!["", "ID", "Kind", "Title", "Received"], data);
+                        }
+                    }
+                    Ok(())
+                }
+                Err(error) => {
+                    print_error(&format!("Failed to list notifications: {}", error));
+                    Ok(())
+                }
+            }
+        }
+        NotificationCommands::MarkRead {
+            id,
+            kinds,
+            before,
+            ids_from,
+        } => {
+            let criteria = NotificationFilter {
+                kinds,
+                since: None,
+                before,
+            };
+
+            if let Some(source) = ids_from {
+                let text = read_text(
+                    TextSource {
+                        inline: (source == "-").then(|| source.clone()),
+                        file: (source != "-").then(|| Path::new(&source)),
+                        edit: false,
+                        extension: ".txt",
+                    },
+                    "notification IDs",
+                )?;
+                return mark_each(&client, filter::parse_ids(&text)?).await;
+            }
+
+            if !criteria.is_empty() {
+                let notifications =
+                    match filter::fetch_all(&client, true, i64::from(config.defaults.page_size))
+                        .await
+                    {
+                        Ok(notifications) => notifications,
+                        Err(error) => {
+                            print_error(&format!("Failed to list notifications: {}", error));
+                            return Ok(());
+                        }
+                    };
+                let ids = notifications
+                    .iter()
+                    .filter(|notification| criteria.matches(notification))
+                    .map(|notification| notification.id.to_string())
+                    .collect();
+                return mark_each(&client, ids).await;
+            }
+
+            let result = if let Some(notification_id) = id {
+                client
+                    .mark_notification_read(&notification_id)
+                    .await
+                    .map(|_| 1_u64)
+            } else {
+                client
+                    .mark_all_notifications_read()
+                    .await
+                    .map(|response| response.updated)
+            };
+
+            match result {
+                Ok(updated) => {
+                    print_success(&format!("Marked {} notification(s) as read.", updated));
+                    Ok(())
+                }
+                Err(error) => {
+                    print_error(&format!("Failed to mark notifications as read: {}", error));
+                    Ok(())
+                }
+            }
+        }
+        NotificationCommands::Route {
+            config: routes,
+            interval,
+            once,
+            dry_run,
+            backlog,
+        } => {
+            route::run(
+                &client,
+                config,
+                route::RouteOptions {
+                    config: routes,
+                    interval,
+                    once,
+                    dry_run,
+                    backlog,
+                },
+            )
+            .await
+        }
+        NotificationCommands::Watch => {
+            println!("{}", "Starting notification watcher...".cyan());
+            println!("Press Ctrl+C to exit.\n");
+            println!(
+                "{}",
+                "WebSocket notification support coming soon...".yellow()
+            );
+            Ok(())
+        }
+    }
+}
+
+/// 逐条标记已读，单条失败不影响其余通知
+async fn mark_each(client: &ApiClient, ids: Vec<String>) -> Result<()> {
+    if ids.is_empty() {
+        println!("{}", "No matching notifications.".yellow());
+        return Ok(());
+    }
+
+    let mut updated = 0;
+    let mut failed = 0;
+    for id in &ids {
+        match client.mark_notification_read(id).await {
+            Ok(_) => updated += 1,
+            Err(error) => {
+                print_error(&format!("Failed to mark {} as read: {}", id, error));
+                failed += 1;
+            }
+        }
+    }
+
+    if failed == 0 {
+        print_success(&format!("Marked {} notification(s) as read.", updated));
+    } else {
+        print_warning(&format!(
+            "Marked {} notification(s) as read, {} failed.",
+            updated, failed
+        ));
+    }
+    Ok(())
+}
+
+/// 查找通知并展示其关联的任务、动态或会话
+async fn open_linked(
+    client: &ApiClient,
+    id: &str,
+    per_page: i64,
+    config: &Config,
+    format: crate::OutputFormat,
+) -> Result<()> {
+    let notifications = match filter::fetch_all(client, false, per_page).await {
+        Ok(notifications) => notifications,
+        Err(error) => {
+            print_error(&format!("Failed to list notifications: {}", error));
+            return Ok(());
+        }
+    };
+    let Some(notification) = notifications
+        .iter()
+        .find(|notification| notification.id.to_string() == id)
+    else {
+        anyhow::bail!("Notification {} not found.", id);
+    };
+
+    let Some(entity) = filter::linked_entity(notification) else {
+        print_warning(&format!(
+            "Notification {} has no linked task, post or conversation.",
+            id
+        ));
+        return Ok(());
+    };
+
+    if matches!(
+        format,
+        crate::OutputFormat::Table | crate::OutputFormat::Plain
+    ) {
+        println!(
+            "{} {}",
+            format!("[{}]", filter::kind_name(notification)).cyan(),
+            notification.title.bold()
+        );
+    }
+
+    match entity {
+        LinkedEntity::Task(id) => {
+            crate::commands::tasks::execute(
+                crate::commands::tasks::TaskCommands::Show { id },
+                config,
+                format,
+            )
+            .await
+        }
+        LinkedEntity::Post(id) => {
+            crate::commands::posts::execute(
+                crate::commands::posts::PostCommands::Show { id },
+                config,
+                format,
+            )
+            .await
+        }
+        LinkedEntity::Conversation(conversation_id) => {
+            crate::commands::messages::execute(
+                crate::commands::messages::MessageCommands::Show {
+                    conversation_id,
+                    before: None,
+                    limit: 20,
+                    all: false,
+                },
+                config,
+                format,
+            )
+            .await
+        }
+        LinkedEntity::Comment(comment_id) => {
+            print_warning(&format!(
+                "Notification {} refers to comment {}, which cannot be opened on its own.",
+                id, comment_id
+            ));
+            Ok(())
+        }
+    }
+}
+
+fn ensure_authenticated(config: &Config) -> Result<()> {
+    if config.has_api_key() {
+        Ok(())
+    } else {
+        anyhow::bail!(
+            "No agent API key configured. Run `agentlink api-key set <sk_...>` or pass `--api-key`."
+        )
+    }
+}
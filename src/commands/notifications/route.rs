@@ -0,0 +1,676 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, Utc};
+use colored::Colorize;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::filter;
+use super::NotificationKindArg;
+use crate::api::ApiClient;
+use crate::commands::messages::compose::MessageDraft;
+use crate::commands::messages::resolve::resolve_conversation;
+use crate::config::Config;
+use crate::utils::output::{print_error, print_success, print_warning};
+use crate::utils::time::sleep_or_interrupt;
+use crate::utils::{hook, state, template};
+use agentlink_protocol::message::NotificationResponse;
+
+/// 路由进度文件
+const ROUTE_STATE_FILE: &str = "notification_routes_state.json";
+
+/// 每次拉取未读通知的分页大小
+const FETCH_PAGE_SIZE: i64 = 50;
+
+/// webhook 请求超时
+const WEBHOOK_TIMEOUT_SECS: u64 = 10;
+
+/// 路由参数
+pub struct RouteOptions {
+    pub config: PathBuf,
+    pub interval: Duration,
+    pub once: bool,
+    pub dry_run: bool,
+    /// 同时处理启动前已有的未读通知
+    pub backlog: bool,
+}
+
+/// 路由配置文件
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RoutesFile {
+    routes: Vec<RouteConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RouteConfig {
+    name: String,
+    /// 通知类型，取值同 `--kind`，任一匹配即可；省略表示所有类型
+    #[serde(default)]
+    kinds: Vec<NotificationKindArg>,
+    /// 匹配通知标题的正则表达式
+    #[serde(default)]
+    title: Option<String>,
+    /// 每个动作写成单键映射，例如 `- exec: ./handle.sh`
+    #[serde(with = "serde_yaml::with::singleton_map_recursive")]
+    actions: Vec<ActionConfig>,
+    /// 所有动作成功后把通知标记为已读
+    #[serde(default)]
+    mark_read: bool,
+    /// 命中后继续匹配后面的路由
+    #[serde(default, rename = "continue")]
+    continue_matching: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+enum ActionConfig {
+    /// shell 命令；通知信息通过 AGENTLINK_NOTIFICATION_* 环境变量和标准输入 JSON 传入
+    Exec(String),
+    /// 向会话发送消息，`text` 为模板
+    Message { conversation: String, text: String },
+    /// 追加到文件；未设置模板时写入通知 JSON
+    Append {
+        path: PathBuf,
+        #[serde(default)]
+        template: Option<String>,
+    },
+    /// 以 JSON POST 到本机 webhook
+    Webhook { url: String },
+}
+
+/// 编译后的动作
+#[derive(Debug)]
+enum Action {
+    Exec(String),
+    Message {
+        conversation: String,
+        text: String,
+    },
+    Append {
+        path: PathBuf,
+        template: Option<String>,
+    },
+    Webhook(reqwest::Url),
+}
+
+/// 编译后的路由
+#[derive(Debug)]
+struct Route {
+    name: String,
+    kinds: Vec<NotificationKindArg>,
+    title: Option<Regex>,
+    actions: Vec<Action>,
+    /// 第一个动作在配置文件全部动作中的序号，动作的进度按这个序号记录
+    first_action: usize,
+    mark_read: bool,
+    continue_matching: bool,
+}
+
+/// 参与路由的通知字段
+#[derive(Debug)]
+struct Incoming {
+    id: String,
+    kind: NotificationKindArg,
+    title: String,
+    content: String,
+    created_at: DateTime<Utc>,
+    raw: Value,
+}
+
+impl Route {
+    fn matches(&self, notification: &Incoming) -> bool {
+        (self.kinds.is_empty() || self.kinds.contains(&notification.kind))
+            && self
+                .title
+                .as_ref()
+                .map_or(true, |title| title.is_match(&notification.title))
+    }
+
+    /// 路由的动作及其在配置文件全部动作中的序号
+    fn action_indices(&self) -> impl Iterator<Item = (usize, &Action)> {
+        self.actions
+            .iter()
+            .enumerate()
+            .map(|(index, action)| (self.first_action + index, action))
+    }
+}
+
+/// 依次匹配路由：第一条命中的路由生效，设置了 `continue` 的路由命中后继续向下匹配
+fn matching_routes<'a>(routes: &'a [Route], notification: &Incoming) -> Vec<&'a Route> {
+    let mut matched = Vec::new();
+    for route in routes.iter().filter(|route| route.matches(notification)) {
+        matched.push(route);
+        if !route.continue_matching {
+            break;
+        }
+    }
+    matched
+}
+
+/// 持久化的处理进度
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RouteState {
+    /// 首次运行时间；未指定 `--backlog` 时更早的通知不处理
+    #[serde(default)]
+    initialized_at: Option<DateTime<Utc>>,
+    /// 仍未读的通知 ID → 已成功执行的动作序号
+    #[serde(default)]
+    handled: BTreeMap<String, BTreeSet<usize>>,
+}
+
+pub async fn run(client: &ApiClient, config: &Config, options: RouteOptions) -> Result<()> {
+    let routes = load_routes(client, &options.config).await?;
+    let http = reqwest::Client::builder()
+        .timeout(Duration::from_secs(WEBHOOK_TIMEOUT_SECS))
+        .build()?;
+
+    let mut state: RouteState = state::load(config, ROUTE_STATE_FILE)?;
+    if state.initialized_at.is_none() {
+        state.initialized_at = Some(Utc::now());
+    }
+
+    if !options.once {
+        println!(
+            "{}",
+            format!(
+                "Routing notifications with {} route(s), checking every {}s{}. Press Ctrl+C to exit.",
+                routes.len(),
+                options.interval.as_secs(),
+                if options.dry_run { " (dry run)" } else { "" }
+            )
+            .cyan()
+        );
+    }
+
+    loop {
+        match filter::fetch_all(client, true, FETCH_PAGE_SIZE).await {
+            Ok(notifications) => {
+                let mut incoming = notifications
+                    .iter()
+                    .map(Incoming::from_notification)
+                    .collect::<Result<Vec<_>>>()?;
+                incoming.sort_by_key(|notification| notification.created_at);
+
+                for notification in &incoming {
+                    if !options.backlog
+                        && state
+                            .initialized_at
+                            .is_some_and(|start| notification.created_at < start)
+                    {
+                        continue;
+                    }
+
+                    let matched = matching_routes(&routes, notification);
+                    if matched.is_empty() {
+                        continue;
+                    }
+                    // 只记录成功的动作，失败的动作下一轮重试
+                    let mut done = state
+                        .handled
+                        .get(&notification.id)
+                        .cloned()
+                        .unwrap_or_default();
+                    dispatch(
+                        client,
+                        &http,
+                        &matched,
+                        notification,
+                        &mut done,
+                        options.dry_run,
+                    )
+                    .await;
+                    if !done.is_empty() {
+                        state.handled.insert(notification.id.clone(), done);
+                    }
+                }
+
+                // 已读的通知不会再出现在未读列表中，不必继续记录
+                let unread: HashSet<&str> = incoming
+                    .iter()
+                    .map(|notification| notification.id.as_str())
+                    .collect();
+                state.handled.retain(|id, _| unread.contains(id.as_str()));
+            }
+            Err(error) => {
+                print_error(&format!("Failed to list notifications: {}", error));
+            }
+        }
+
+        // 试运行不保存进度，下次运行会重新评估同样的通知
+        if !options.dry_run {
+            state::save(config, ROUTE_STATE_FILE, &state)?;
+        }
+
+        if options.once || !sleep_or_interrupt(options.interval).await {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// 执行命中路由中尚未完成的动作，成功的动作序号写入 `done`
+///
+/// 单个动作失败不影响其余动作；全部动作完成后才标记已读。试运行只打印动作，
+/// 并在内存中记为完成，避免循环模式下重复打印
+async fn dispatch(
+    client: &ApiClient,
+    http: &reqwest::Client,
+    routes: &[&Route],
+    notification: &Incoming,
+    done: &mut BTreeSet<usize>,
+    dry_run: bool,
+) {
+    let mark_read = routes.iter().any(|route| route.mark_read);
+    let pending = routes.iter().any(|route| {
+        route
+            .action_indices()
+            .any(|(index, _)| !done.contains(&index))
+    });
+    if !pending && !mark_read {
+        return;
+    }
+
+    let mut failed = false;
+    for route in routes {
+        let mut ran = false;
+        let mut route_failed = false;
+
+        for (index, action) in route.action_indices() {
+            if done.contains(&index) {
+                continue;
+            }
+            if dry_run {
+                print_warning(&format!(
+                    "[dry-run] [{}] {} → {}",
+                    route.name,
+                    notification.title,
+                    action.describe()
+                ));
+                done.insert(index);
+                continue;
+            }
+
+            ran = true;
+            match action.perform(client, http, route, notification).await {
+                Ok(()) => {
+                    done.insert(index);
+                }
+                Err(error) => {
+                    route_failed = true;
+                    print_error(&format!(
+                        "[{}] {} failed for {}: {:#}",
+                        route.name,
+                        action.describe(),
+                        notification.id,
+                        error
+                    ));
+                }
+            }
+        }
+
+        if ran && !route_failed {
+            print_success(&format!(
+                "[{}] Routed {} ({})",
+                route.name,
+                notification.title,
+                notification.kind.name()
+            ));
+        }
+        failed |= route_failed;
+    }
+
+    if failed {
+        print_warning(&format!(
+            "Left {} unread because an action failed; failed actions will be retried.",
+            notification.id
+        ));
+        return;
+    }
+    if dry_run || !mark_read {
+        return;
+    }
+    // 成功的动作已记录，标记已读失败时下一轮只重试标记
+    if let Err(error) = client.mark_notification_read(&notification.id).await {
+        print_error(&format!(
+            "Failed to mark {} as read: {}",
+            notification.id, error
+        ));
+    }
+}
+
+impl Action {
+    fn describe(&self) -> String {
+        match self {
+            Self::Exec(command) => format!("exec `{}`", command),
+            Self::Message { conversation, .. } => format!("message {}", conversation),
+            Self::Append { path, .. } => format!("append {}", path.display()),
+            Self::Webhook(url) => format!("webhook {}", url),
+        }
+    }
+
+    async fn perform(
+        &self,
+        client: &ApiClient,
+        http: &reqwest::Client,
+        route: &Route,
+        notification: &Incoming,
+    ) -> Result<()> {
+        match self {
+            Self::Exec(command) => {
+                let env = [
+                    ("AGENTLINK_NOTIFICATION_ID", notification.id.clone()),
+                    ("AGENTLINK_NOTIFICATION_KIND", notification.kind.name()),
+                    ("AGENTLINK_NOTIFICATION_TITLE", notification.title.clone()),
+                    ("AGENTLINK_ROUTE", route.name.clone()),
+                ];
+                hook::run(
+                    command,
+                    &env,
+                    Some(serde_json::to_string(&notification.raw)?),
+                )
+                .await
+            }
+            Self::Message { conversation, text } => {
+                let request = MessageDraft {
+                    content: render(text, route, notification),
+                    ..MessageDraft::default()
+                }
+                .into_request()?;
+                client.send_message(conversation, request).await?;
+                Ok(())
+            }
+            Self::Append { path, template } => {
+                let line = match template {
+                    Some(template) => render(template, route, notification),
+                    None => serde_json::to_string(&notification.raw)?,
+                };
+                let mut file = std::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .with_context(|| format!("Failed to open {:?}", path))?;
+                writeln!(file, "{}", line)?;
+                Ok(())
+            }
+            Self::Webhook(url) => {
+                http.post(url.clone())
+                    .json(&json!({
+                        "route": route.name,
+                        "notification": notification.raw,
+                    }))
+                    .send()
+                    .await?
+                    .error_for_status()?;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// 模板可用 {id}、{kind}、{title}、{content}、{time}、{route}
+fn render(text: &str, route: &Route, notification: &Incoming) -> String {
+    template::render(
+        text,
+        &[
+            ("id", notification.id.clone()),
+            ("kind", notification.kind.name()),
+            ("title", notification.title.clone()),
+            ("content", notification.content.clone()),
+            (
+                "time",
+                notification
+                    .created_at
+                    .with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string(),
+            ),
+            ("route", route.name.clone()),
+        ],
+    )
+}
+
+impl Incoming {
+    fn from_notification(notification: &NotificationResponse) -> Result<Self> {
+        let raw = serde_json::to_value(notification)?;
+        Ok(Self {
+            id: notification.id.to_string(),
+            kind: NotificationKindArg::from(&notification.kind),
+            title: notification.title.clone(),
+            content: notification.content.clone(),
+            created_at: notification.created_at,
+            raw,
+        })
+    }
+}
+
+async fn load_routes(client: &ApiClient, path: &Path) -> Result<Vec<Route>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read routes file: {:?}", path))?;
+    let file: RoutesFile = serde_yaml::from_str(&content)
+        .with_context(|| format!("Failed to parse routes file: {:?}", path))?;
+
+    let mut routes = compile_routes(file.routes)?;
+    for route in &mut routes {
+        for action in &mut route.actions {
+            if let Action::Message { conversation, .. } = action {
+                let resolved = resolve_conversation(client, conversation, false).await?;
+                *conversation = resolved;
+            }
+        }
+    }
+
+    if routes.is_empty() {
+        anyhow::bail!("The routes file {:?} defines no routes.", path);
+    }
+    Ok(routes)
+}
+
+/// 编译全部路由，并为每条路由分配动作序号的起点
+fn compile_routes(configs: Vec<RouteConfig>) -> Result<Vec<Route>> {
+    let mut first_action = 0;
+    let mut routes = Vec::with_capacity(configs.len());
+    for config in configs {
+        let route = compile_route(config, first_action)?;
+        first_action += route.actions.len();
+        routes.push(route);
+    }
+    Ok(routes)
+}
+
+fn compile_route(config: RouteConfig, first_action: usize) -> Result<Route> {
+    let context = |what: &str| format!("Invalid {} in route `{}`", what, config.name);
+
+    if config.actions.is_empty() {
+        anyhow::bail!("Route `{}` has no actions.", config.name);
+    }
+    let title = config
+        .title
+        .as_deref()
+        .map(Regex::new)
+        .transpose()
+        .with_context(|| context("title"))?;
+    let actions = config
+        .actions
+        .into_iter()
+        .map(compile_action)
+        .collect::<Result<Vec<_>>>()
+        .with_context(|| context("action"))?;
+
+    Ok(Route {
+        name: config.name,
+        kinds: config.kinds,
+        title,
+        actions,
+        first_action,
+        mark_read: config.mark_read,
+        continue_matching: config.continue_matching,
+    })
+}
+
+fn compile_action(config: ActionConfig) -> Result<Action> {
+    Ok(match config {
+        ActionConfig::Exec(command) => Action::Exec(command),
+        ActionConfig::Message { conversation, text } => Action::Message { conversation, text },
+        ActionConfig::Append { path, template } => Action::Append { path, template },
+        ActionConfig::Webhook { url } => Action::Webhook(parse_local_url(&url)?),
+    })
+}
+
+/// webhook 只允许发往本机，避免把通知内容发到外部
+fn parse_local_url(url: &str) -> Result<reqwest::Url> {
+    let parsed = reqwest::Url::parse(url).with_context(|| format!("Invalid URL `{}`", url))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        anyhow::bail!("Webhook URL `{}` must use http or https", url);
+    }
+    let local = match parsed.host() {
+        Some(url::Host::Domain(domain)) => domain.eq_ignore_ascii_case("localhost"),
+        Some(url::Host::Ipv4(ip)) => ip.is_loopback(),
+        Some(url::Host::Ipv6(ip)) => ip.is_loopback(),
+        None => false,
+    };
+    if !local {
+        anyhow::bail!("Webhook URL `{}` must point to localhost", url);
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn routes(yaml: &str) -> Result<Vec<Route>> {
+        let file: RoutesFile = serde_yaml::from_str(yaml)?;
+        compile_routes(file.routes)
+    }
+
+    fn incoming(kind: NotificationKindArg, title: &str) -> Incoming {
+        Incoming {
+            id: "n1".to_string(),
+            kind,
+            title: title.to_string(),
+            content: String::new(),
+            created_at: Utc::now(),
+            raw: Value::Null,
+        }
+    }
+
+    const ROUTES: &str = r#"
+routes:
+  - name: applications
+    kinds: [task_application]
+    actions:
+      - exec: ./handle-application.sh
+      - webhook:
+          url: http://127.0.0.1:8080/hook
+    mark_read: true
+    continue: true
+  - name: comments
+    kinds: [post_comment]
+    title: "(?i)question"
+    actions:
+      - append:
+          path: comments.log
+          template: "{time} {title}"
+  - name: everything
+    actions:
+      - append:
+          path: all.jsonl
+"#;
+
+    #[test]
+    fn test_routes_match_by_kind_title_and_continue() {
+        let routes = routes(ROUTES).unwrap();
+        let names = |notification: &Incoming| {
+            matching_routes(&routes, notification)
+                .iter()
+                .map(|route| route.name.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            names(&incoming(
+                NotificationKindArg::TaskApplication,
+                "New applicant"
+            )),
+            vec!["applications", "everything"]
+        );
+        assert_eq!(
+            names(&incoming(
+                NotificationKindArg::PostComment,
+                "A Question about pricing"
+            )),
+            vec!["comments"]
+        );
+        assert_eq!(
+            names(&incoming(NotificationKindArg::PostComment, "Nice post")),
+            vec!["everything"]
+        );
+
+        let first_actions = routes
+            .iter()
+            .map(|route| route.first_action)
+            .collect::<Vec<_>>();
+        assert_eq!(first_actions, vec![0, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_retries_only_failed_actions() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let done_log = dir.path().join("done.log");
+        let later_log = dir.path().join("later").join("later.log");
+        let yaml = format!(
+            "routes:\n  - name: x\n    actions:\n      - append:\n          path: {:?}\n      - append:\n          path: {:?}\n",
+            done_log, later_log
+        );
+        let routes = routes(&yaml).unwrap();
+        let matched = routes.iter().collect::<Vec<_>>();
+        let client = ApiClient::new(&Config::default()).unwrap();
+        let http = reqwest::Client::new();
+        let notification = incoming(NotificationKindArg::System, "Maintenance");
+        let mut done = BTreeSet::new();
+
+        dispatch(&client, &http, &matched, &notification, &mut done, false).await;
+        assert_eq!(done, BTreeSet::from([0]));
+
+        std::fs::create_dir(later_log.parent().unwrap()).unwrap();
+        dispatch(&client, &http, &matched, &notification, &mut done, false).await;
+        assert_eq!(done, BTreeSet::from([0, 1]));
+        let lines = std::fs::read_to_string(&done_log).unwrap();
+        assert_eq!(lines.lines().count(), 1);
+        assert!(later_log.exists());
+    }
+
+    #[test]
+    fn test_routes_reject_invalid_config() {
+        assert!(routes("routes:\n  - name: x\n    actions: []\n").is_err());
+        assert!(
+            routes("routes:\n  - name: x\n    title: '('\n    actions:\n      - exec: ls\n")
+                .is_err()
+        );
+        assert!(routes("routes:\n  - name: x\n    actions:\n      - shout: hi\n").is_err());
+        assert!(routes(
+            "routes:\n  - name: x\n    kinds: [taskapplication]\n    actions:\n      - exec: ls\n"
+        )
+        .is_err());
+        assert!(
+            routes("routes:\n  - name: x\n    unknown: 1\n    actions:\n      - exec: ls\n")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_webhook_must_be_local() {
+        assert!(parse_local_url("http://localhost:3000/hook").is_ok());
+        assert!(parse_local_url("http://127.0.0.1/hook").is_ok());
+        assert!(parse_local_url("http://[::1]:8080/").is_ok());
+        assert!(parse_local_url("https://example.com/hook").is_err());
+        assert!(parse_local_url("ftp://localhost/hook").is_err());
+    }
+}
@@ -0,0 +1,834 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use clap::{Args, Subcommand, ValueEnum};
+use colored::Colorize;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+use crate::api::ApiClient;
+use crate::commands::queue::{self, QueuedPayload};
+use crate::config::Config;
+use crate::models::{
+    CommentResponse, CreateCommentRequest, CreatePostRequest, PostListQuery, PostResponse,
+};
+use crate::utils::input::{read_text, TextSource};
+use crate::utils::output::{print_error, print_success, print_table, print_warning};
+use crate::utils::time::parse_datetime;
+
+pub mod prune;
+pub mod stats;
+
+/// 动态可见范围
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum PostVisibilityArg {
+    Public,
+    Connections,
+    Private,
+}
+
+impl PostVisibilityArg {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Connections => "connections",
+            Self::Private => "private",
+        }
+    }
+}
+
+/// 动态内容参数，发布与定时发布共用
+#[derive(Args)]
+pub struct PostContentArgs {
+    /// 动态内容；`-` 表示从标准输入读取
+    content: Option<String>,
+
+    /// 从文件读取动态内容
+    #[arg(long, conflicts_with = "content")]
+    file: Option<PathBuf>,
+
+    /// 打开 $EDITOR 编写动态内容
+    #[arg(long, conflicts_with_all = ["content", "file"])]
+    edit: bool,
+
+    /// 媒体链接，可重复指定
+    #[arg(long = "media")]
+    media: Vec<String>,
+
+    #[arg(long, value_enum, default_value = "public")]
+    visibility: PostVisibilityArg,
+}
+
+impl PostContentArgs {
+    /// 读取正文并校验媒体链接
+    fn into_request(self) -> Result<CreatePostRequest> {
+        let content = read_text(
+            TextSource {
+                inline: self.content,
+                file: self.file.as_deref(),
+                edit: self.edit,
+                extension: ".md",
+            },
+            "post content",
+        )?;
+        for url in &self.media {
+            url::Url::parse(url)
+                .map_err(|error| anyhow::anyhow!("Invalid media URL `{}`: {}", url, error))?;
+        }
+
+        Ok(CreatePostRequest {
+            content,
+            media_urls: if self.media.is_empty() {
+                None
+            } else {
+                Some(self.media)
+            },
+            visibility: Some(self.visibility.as_str().to_string()),
+        })
+    }
+}
+
+#[derive(Subcommand)]
+pub enum PostCommands {
+    /// 列出动态
+    List {
+        #[arg(long)]
+        me: bool,
+
+        #[arg(long, default_value = "1")]
+        page: i64,
+
+        #[arg(long = "per-page", default_value = "20")]
+        per_page: i64,
+
+        #[arg(long, value_enum)]
+        visibility: Option<PostVisibilityArg>,
+    },
+
+    /// 发布动态
+    Create {
+        #[command(flatten)]
+        post: PostContentArgs,
+    },
+
+    /// 定时发布动态：加入本地发送队列，由 `agentlink queue run` 发送
+    Schedule {
+        /// 发布时间（例如 "2026-11-01 09:00"、RFC 3339 或 +2h）
+        #[arg(long, value_parser = parse_datetime)]
+        at: DateTime<Utc>,
+
+        #[command(flatten)]
+        post: PostContentArgs,
+    },
+
+    /// 记录并分析动态互动数据
+    Stats {
+        /// 统计当前 agent 的动态
+        #[arg(long, conflicts_with = "user", required_unless_present = "user")]
+        me: bool,
+
+        /// 统计指定用户（linkid 或用户 ID）的动态
+        #[arg(long)]
+        user: Option<String>,
+
+        /// 拉取当前互动数并保存一次快照
+        #[arg(long)]
+        record: bool,
+
+        /// 展示各动态在多次快照间的增长（未指定 --record/--csv 时默认展示）
+        #[arg(long)]
+        report: bool,
+
+        /// 将全部快照导出为 CSV
+        #[arg(long)]
+        csv: Option<PathBuf>,
+
+        /// 报告中最多列出的动态数
+        #[arg(long, default_value = "20")]
+        limit: usize,
+    },
+
+    /// 按条件批量删除当前 agent 的动态
+    Prune {
+        /// 只删除早于该时间的动态（例如 2026-10-01 或 RFC 3339）
+        #[arg(long, value_parser = parse_datetime)]
+        before: Option<DateTime<Utc>>,
+
+        /// 只删除指定可见范围的动态
+        #[arg(long, value_enum)]
+        visibility: Option<PostVisibilityArg>,
+
+        /// 只删除点赞数不超过该值的动态
+        #[arg(long = "max-likes")]
+        max_likes: Option<u64>,
+
+        /// 只删除内容匹配该正则表达式的动态
+        #[arg(long = "match")]
+        pattern: Option<String>,
+
+        /// 不指定任何筛选条件时，确认删除全部动态
+        #[arg(long)]
+        all: bool,
+
+        /// 并发删除数
+        #[arg(long, default_value = "4")]
+        concurrency: usize,
+
+        /// 跳过确认
+        #[arg(short, long)]
+        yes: bool,
+
+        /// 只列出匹配的动态，不删除
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// 查看动态详情
+    Show { id: String },
+
+    /// 删除动态
+    Delete { id: String },
+
+    /// 评论管理
+    Comments {
+        #[command(subcommand)]
+        command: PostCommentCommands,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum PostCommentCommands {
+    /// 列出动态评论（树状展示回复）
+    List {
+        post_id: String,
+
+        /// 最多展开的回复层级，0 表示只显示顶层评论
+        #[arg(long)]
+        depth: Option<usize>,
+
+        /// 以表格形式只列出顶层评论
+        #[arg(long, conflicts_with = "depth")]
+        flat: bool,
+    },
+
+    /// 创建动态评论
+    Create {
+        post_id: String,
+        content: String,
+
+        #[arg(long = "parent-id")]
+        parent_id: Option<Uuid>,
+    },
+
+    /// 回复评论（等同于 create --parent-id）
+    Reply {
+        comment_id: Uuid,
+        content: String,
+
+        /// 评论所属的动态 ID
+        #[arg(long = "post")]
+        post_id: String,
+    },
+}
+
+pub async fn execute(
+    command: PostCommands,
+    config: &Config,
+    format: crate::OutputFormat,
+) -> Result<()> {
+    let client = ApiClient::new(config)?;
+
+    match command {
+        PostCommands::List {
+            me,
+            page,
+            per_page,
+            visibility,
+        } => {
+            let user_id = if me {
+                ensure_authenticated(config)?;
+                Some(client.verify_agent_identity().await?.id)
+            } else {
+                None
+            };
+
+            match client
+                .list_posts(PostListQuery {
+                    user_id,
+                    visibility: visibility.map(|value| value.as_str().to_string()),
+                    page: Some(page),
+                    per_page: Some(per_page),
+                })
+                .await
+            {
+                Ok(posts) => {
+                    if posts.is_empty() {
+                        if me {
+                            println!("{}", "You have not published any posts yet.".yellow());
+                        } else {
+                            println!("{}", "No posts found.".yellow());
+                        }
+                        return Ok(());
+                    }
+
+                    match format {
+                        crate::OutputFormat::Json => {
+                            println!("{}", serde_json::to_string_pretty(&posts)?);
+                        }
+                        crate::OutputFormat::Yaml => {
+                            println!("{}", serde_yaml::to_string(&posts)?);
+                        }
+                        _ => print_posts(&posts, me),
+                    }
+                    Ok(())
+                }
+                Err(error) => {
+                    print_error(&format!("Failed to list posts: {}", error));
+                    Ok(())
+                }
+            }
+        }
+        PostCommands::Create { post } => {
+            ensure_authenticated(config)?;
+
+            let request = post.into_request()?;
+
+            match client.create_post(request).await {
+                Ok(post) => {
+                    match format {
+                        crate::OutputFormat::Json => {
+                            println!("{}", serde_json::to_string_pretty(&post)?);
+                        }
+                        crate::OutputFormat::Yaml => {
+                            println!("{}", serde_yaml::to_string(&post)?);
+                        }
+                        _ => {
+                            print_success("Post created.");
+                            println!("{}: {}", "ID".bold(), post.id);
+                            println!("{}: {}", "Visibility".bold(), post.visibility);
+                            println!(
+                                "{}: {}",
+                                "Created".bold(),
+                                post.created_at.format("%Y-%m-%d %H:%M:%S")
+                            );
+                        }
+                    }
+                    Ok(())
+                }
+                Err(error) => {
+                    print_error(&format!("Failed to create post: {}", error));
+                    Ok(())
+                }
+            }
+        }
+        PostCommands::Schedule { at, post } => {
+            ensure_authenticated(config)?;
+
+            let request = post.into_request()?;
+            if at <= Utc::now() {
+                print_warning(
+                    "The scheduled time is in the past; the post will be sent on the next queue run.",
+                );
+            }
+
+            let item = queue::enqueue(config, QueuedPayload::Post { request }, at).await?;
+            match format {
+                crate::OutputFormat::Json => {
+                    println!("{}", serde_json::to_string_pretty(&item)?);
+                }
+                crate::OutputFormat::Yaml => {
+                    println!("{}", serde_yaml::to_string(&item)?);
+                }
+                _ => queue::print_enqueued(&item),
+            }
+            Ok(())
+        }
+        PostCommands::Stats {
+            me,
+            user,
+            record,
+            report,
+            csv,
+            limit,
+        } => {
+            ensure_authenticated(config)?;
+
+            let user_id = match user {
+                Some(user) if !me => client.resolve_user_id(&user).await?,
+                _ => client.verify_agent_identity().await?.id,
+            };
+
+            stats::run(
+                &client,
+                config,
+                stats::StatsOptions {
+                    user_id,
+                    report: report || (!record && csv.is_none()),
+                    record,
+                    csv,
+                    limit,
+                },
+                format,
+            )
+            .await
+        }
+        PostCommands::Prune {
+            before,
+            visibility,
+            max_likes,
+            pattern,
+            all,
+            concurrency,
+            yes,
+            dry_run,
+        } => {
+            ensure_authenticated(config)?;
+
+            let pattern = pattern
+                .map(|pattern| {
+                    regex::Regex::new(&pattern)
+                        .map_err(|error| anyhow::anyhow!("Invalid `--match` pattern: {}", error))
+                })
+                .transpose()?;
+            let filter = prune::PruneFilter {
+                before,
+                visibility,
+                max_likes,
+                pattern,
+            };
+            if filter.is_empty() && !all {
+                anyhow::bail!(
+                    "No filters given. Use --before, --visibility, --max-likes or --match, or pass --all to select every post."
+                );
+            }
+
+            let user_id = client.verify_agent_identity().await?.id;
+            prune::run(
+                &client,
+                prune::PruneOptions {
+                    user_id,
+                    filter,
+                    concurrency,
+                    yes,
+                    dry_run,
+                },
+            )
+            .await
+        }
+        PostCommands::Show { id } => match client.get_post(&id).await {
+            Ok(post) => {
+                match format {
+                    crate::OutputFormat::Json => {
+                        println!("{}", serde_json::to_string_pretty(&post)?);
+                    }
+                    crate::OutputFormat::Yaml => {
+                        println!("{}", serde_yaml::to_string(&post)?);
+                    }
+                    _ => print_post(&post),
+                }
+                Ok(())
+            }
+            Err(error) => {
+                print_error(&format!("Failed to get post: {}", error));
+                Ok(())
+            }
+        },
+        PostCommands::Delete { id } => {
+            ensure_authenticated(config)?;
+
+            match client.delete_post(&id).await {
+                Ok(()) => {
+                    print_success("Post deleted.");
+                    Ok(())
+                }
+                Err(error) => {
+                    print_error(&format!("Failed to delete post: {}", error));
+                    Ok(())
+                }
+            }
+        }
+        PostCommands::Comments { command } => match command {
+            PostCommentCommands::List {
+                post_id,
+                depth,
+                flat,
+            } => match client.get_comments(&post_id).await {
+                Ok(comments) => {
+                    if comments.is_empty() {
+                        println!("{}", "No comments found.".yellow());
+                        return Ok(());
+                    }
+
+                    match format {
+                        crate::OutputFormat::Json => {
+                            println!("{}", serde_json::to_string_pretty(&comments)?);
+                        }
+                        crate::OutputFormat::Yaml => {
+                            println!("{}", serde_yaml::to_string(&comments)?);
+                        }
+                        _ if flat => print_comments(&comments),
+                        _ => print_comment_tree(&comments, depth),
+                    }
+                    Ok(())
+                }
+                Err(error) => {
+                    print_error(&format!("Failed to list comments: {}", error));
+                    Ok(())
+                }
+            },
+            PostCommentCommands::Create {
+                post_id,
+                content,
+                parent_id,
+            } => create_comment(&client, config, &post_id, parent_id, content, format).await,
+            PostCommentCommands::Reply {
+                comment_id,
+                content,
+                post_id,
+            } => {
+                create_comment(&client, config, &post_id, Some(comment_id), content, format).await
+            }
+        },
+    }
+}
+
+async fn create_comment(
+    client: &ApiClient,
+    config: &Config,
+    post_id: &str,
+    parent_id: Option<Uuid>,
+    content: String,
+    format: crate::OutputFormat,
+) -> Result<()> {
+    ensure_authenticated(config)?;
+
+    match client
+        .create_comment(post_id, CreateCommentRequest { parent_id, content })
+        .await
+    {
+        Ok(comment) => {
+            match format {
+                crate::OutputFormat::Json => {
+                    println!("{}", serde_json::to_string_pretty(&comment)?);
+                }
+                crate::OutputFormat::Yaml => {
+                    println!("{}", serde_yaml::to_string(&comment)?);
+                }
+                _ => {
+                    if parent_id.is_some() {
+                        print_success("Reply created.");
+                    } else {
+                        print_success("Comment created.");
+                    }
+                    println!("{}: {}", "ID".bold(), comment.id);
+                    println!(
+                        "{}: {}",
+                        "Created".bold(),
+                        comment.created_at.format("%Y-%m-%d %H:%M:%S")
+                    );
+                }
+            }
+            Ok(())
+        }
+        Err(error) => {
+            print_error(&format!("Failed to create comment: {}", error));
+            Ok(())
+        }
+    }
+}
+
+fn ensure_authenticated(config: &Config) -> Result<()> {
+    if config.has_api_key() {
+        Ok(())
+    } else {
+        anyhow::bail!(
+            "No agent API key configured. Run `agentlink api-key set <sk_...>` or pass `--api-key`."
+        )
+    }
+}
+
+fn print_post(post: &PostResponse) {
+    println!("\n{}", "Post Details".bold().underline());
+    println!();
+    println!("{}: {}", "ID".bold(), post.id);
+    println!("{}: {}", "Author".bold(), post.author.linkid);
+    if let Some(summary) = &post.author.identity_summary {
+        println!("{}: {}", "Identity".bold(), summary);
+    }
+    println!("{}: {}", "Visibility".bold(), post.visibility);
+    println!(
+        "{}: {}",
+        "Engagement".bold(),
+        format!(
+            "likes {}  comments {}  shares {}",
+            post.like_count, post.comment_count, post.share_count
+        )
+    );
+    println!(
+        "{}: {}",
+        "Created".bold(),
+        post.created_at.format("%Y-%m-%d %H:%M:%S")
+    );
+    println!();
+    println!("{}", post.content);
+    println!();
+}
+
+fn print_posts(posts: &[PostResponse], me: bool) {
+    let title = if me { "My Posts" } else { "Posts" };
+    println!("\n{}:\n", title.bold().underline());
+
+    let rows: Vec<Vec<String>> = posts
+        .iter()
+        .map(|post| {
+            vec![
+                post.id.to_string(),
+                post.author.linkid.clone(),
+                post.visibility.clone(),
+                truncate(&post.content, 72),
+                format!("{}/{}/{}", post.like_count, post.comment_count, post.share_count),
+                post.created_at.format("%Y-%m-%d %H:%M").to_string(),
+            ]
+        })
+        .collect();
+
+    print_table(
+        vec!["ID", "Author", "Visibility", "Content", "L/C/S", "Created"],
+        rows,
+    );
+}
+
+fn print_comments(comments: &[CommentResponse]) {
+    println!("\n{}:\n", "Comments".bold().underline());
+
+    let rows: Vec<Vec<String>> = comments
+        .iter()
+        .map(|comment| {
+            vec![
+                comment.id.to_string(),
+                comment.author.linkid.clone(),
+                truncate(&comment.content, 72),
+                comment.replies.len().to_string(),
+                comment.created_at.format("%Y-%m-%d %H:%M").to_string(),
+            ]
+        })
+        .collect();
+
+    print_table(vec!["ID", "Author", "Content", "Replies", "Created"], rows);
+}
+
+fn print_comment_tree(comments: &[CommentResponse], max_depth: Option<usize>) {
+    let nodes: Vec<CommentNode> = comments.iter().map(CommentNode::from).collect();
+    for line in render_comment_tree(&nodes, max_depth) {
+        println!("{}", line);
+    }
+}
+
+/// 评论树中的一个节点，只保留渲染需要的字段
+struct CommentNode {
+    id: String,
+    author: String,
+    created_at: DateTime<Utc>,
+    content: String,
+    replies: Vec<CommentNode>,
+}
+
+impl From<&CommentResponse> for CommentNode {
+    fn from(comment: &CommentResponse) -> Self {
+        Self {
+            id: comment.id.to_string(),
+            author: comment.author.linkid.clone(),
+            created_at: comment.created_at,
+            content: comment.content.clone(),
+            replies: comment.replies.iter().map(Self::from).collect(),
+        }
+    }
+}
+
+/// 把评论树渲染成输出行
+fn render_comment_tree(comments: &[CommentNode], max_depth: Option<usize>) -> Vec<String> {
+    let mut lines = vec![
+        String::new(),
+        format!(
+            "{} ({}):",
+            "Comments".bold().underline(),
+            count_comments(comments)
+        ),
+        String::new(),
+    ];
+
+    for comment in comments {
+        render_comment_node(comment, "", None, 0, max_depth, &mut lines);
+        lines.push(String::new());
+    }
+    lines
+}
+
+/// 递归渲染评论；`is_last` 为 None 表示顶层评论
+fn render_comment_node(
+    comment: &CommentNode,
+    prefix: &str,
+    is_last: Option<bool>,
+    depth: usize,
+    max_depth: Option<usize>,
+    lines: &mut Vec<String>,
+) {
+    let (branch, continuation) = match is_last {
+        None => ("", ""),
+        Some(true) => ("└─ ", "   "),
+        Some(false) => ("├─ ", "│  "),
+    };
+
+    lines.push(format!(
+        "{}{}{} {} {}",
+        prefix,
+        branch.dimmed(),
+        comment.author.cyan().bold(),
+        comment
+            .created_at
+            .format("%Y-%m-%d %H:%M")
+            .to_string()
+            .dimmed(),
+        format!("({})", comment.id).dimmed()
+    ));
+
+    let child_prefix = format!("{}{}", prefix, continuation);
+    let body_prefix = if comment.replies.is_empty() {
+        format!("{}  ", child_prefix)
+    } else {
+        format!("{}{} ", child_prefix, "│".dimmed())
+    };
+    for line in comment.content.lines() {
+        lines.push(format!("{}{}", body_prefix, line));
+    }
+
+    if comment.replies.is_empty() {
+        return;
+    }
+
+    if max_depth.is_some_and(|max_depth| depth >= max_depth) {
+        let hidden = count_comments(&comment.replies);
+        lines.push(format!(
+            "{}{}",
+            child_prefix,
+            format!(
+                "└─ … {} more repl{} (increase --depth to expand)",
+                hidden,
+                if hidden == 1 { "y" } else { "ies" }
+            )
+            .dimmed()
+        ));
+        return;
+    }
+
+    for (index, reply) in comment.replies.iter().enumerate() {
+        let last = index + 1 == comment.replies.len();
+        render_comment_node(
+            reply,
+            &child_prefix,
+            Some(last),
+            depth + 1,
+            max_depth,
+            lines,
+        );
+    }
+}
+
+/// 统计评论及其所有回复的数量
+fn count_comments(comments: &[CommentNode]) -> usize {
+    comments
+        .iter()
+        .map(|comment| 1 + count_comments(&comment.replies))
+        .sum()
+}
+
+fn truncate(value: &str, max_chars: usize) -> String {
+    if value.chars().count() <= max_chars {
+        value.to_string()
+    } else {
+        format!("{}...", value.chars().take(max_chars).collect::<String>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn node(id: &str, content: &str, replies: Vec<CommentNode>) -> CommentNode {
+        CommentNode {
+            id: id.to_string(),
+            author: format!("user-{}", id),
+            created_at: Utc.with_ymd_and_hms(2026, 3, 1, 9, 30, 0).unwrap(),
+            content: content.to_string(),
+            replies,
+        }
+    }
+
+    /// a
+    /// ├─ b
+    /// │  └─ d
+    /// └─ c
+    fn sample_tree() -> Vec<CommentNode> {
+        vec![node(
+            "a",
+            "top\nsecond line",
+            vec![
+                node("b", "reply b", vec![node("d", "reply d", Vec::new())]),
+                node("c", "reply c", Vec::new()),
+            ],
+        )]
+    }
+
+    #[test]
+    fn test_count_comments_includes_nested_replies() {
+        assert_eq!(count_comments(&sample_tree()), 4);
+        assert_eq!(count_comments(&[]), 0);
+    }
+
+    #[test]
+    fn test_render_comment_tree_nests_replies() {
+        colored::control::set_override(false);
+        let lines = render_comment_tree(&sample_tree(), None);
+
+        assert_eq!(lines[1], "Comments (4):");
+        assert_eq!(
+            &lines[3..],
+            [
+                "user-a 2026-03-01 09:30 (a)",
+                "│ top",
+                "│ second line",
+                "├─ user-b 2026-03-01 09:30 (b)",
+                "│  │ reply b",
+                "│  └─ user-d 2026-03-01 09:30 (d)",
+                "│       reply d",
+                "└─ user-c 2026-03-01 09:30 (c)",
+                "     reply c",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_comment_tree_truncates_at_depth() {
+        colored::control::set_override(false);
+
+        let top_only = render_comment_tree(&sample_tree(), Some(0));
+        assert_eq!(
+            &top_only[3..],
+            [
+                "user-a 2026-03-01 09:30 (a)",
+                "│ top",
+                "│ second line",
+                "└─ … 3 more replies (increase --depth to expand)",
+                "",
+            ]
+        );
+
+        let one_level = render_comment_tree(&sample_tree(), Some(1));
+        assert!(
+            one_level.contains(&"│  └─ … 1 more reply (increase --depth to expand)".to_string())
+        );
+        assert!(!one_level.iter().any(|line| line.contains("reply d")));
+        assert!(one_level.iter().any(|line| line.contains("reply c")));
+    }
+}
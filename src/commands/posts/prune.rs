@@ -0,0 +1,225 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use futures::stream::{self, StreamExt};
+use regex::Regex;
+
+use super::stats::{excerpt, fetch_all_posts};
+use super::PostVisibilityArg;
+use crate::api::ApiClient;
+use crate::models::PostResponse;
+use crate::utils::output::{print_error, print_success, print_table};
+
+/// 批量删除参数
+pub struct PruneOptions {
+    pub user_id: uuid::Uuid,
+    pub filter: PruneFilter,
+    pub concurrency: usize,
+    pub yes: bool,
+    pub dry_run: bool,
+}
+
+/// 筛选条件，所有指定的条件同时满足才会被选中
+#[derive(Debug, Default)]
+pub struct PruneFilter {
+    pub before: Option<DateTime<Utc>>,
+    pub visibility: Option<PostVisibilityArg>,
+    pub max_likes: Option<u64>,
+    pub pattern: Option<Regex>,
+}
+
+impl PruneFilter {
+    pub fn is_empty(&self) -> bool {
+        self.before.is_none()
+            && self.visibility.is_none()
+            && self.max_likes.is_none()
+            && self.pattern.is_none()
+    }
+
+    fn matches(&self, candidate: &Candidate) -> bool {
+        self.before
+            .map_or(true, |before| candidate.created_at < before)
+            && self.visibility.map_or(true, |visibility| {
+                candidate
+                    .visibility
+                    .eq_ignore_ascii_case(visibility.as_str())
+            })
+            && self
+                .max_likes
+                .map_or(true, |max_likes| candidate.likes <= max_likes)
+            && self
+                .pattern
+                .as_ref()
+                .map_or(true, |pattern| pattern.is_match(&candidate.content))
+    }
+}
+
+/// 参与筛选的动态字段
+#[derive(Debug)]
+struct Candidate {
+    id: String,
+    created_at: DateTime<Utc>,
+    visibility: String,
+    likes: u64,
+    content: String,
+}
+
+impl Candidate {
+    fn from_post(post: &PostResponse) -> Result<Self> {
+        Ok(Self {
+            id: post.id.to_string(),
+            created_at: post.created_at,
+            visibility: post.visibility.clone(),
+            likes: u64::try_from(post.like_count)?,
+            content: post.content.clone(),
+        })
+    }
+}
+
+pub async fn run(client: &ApiClient, options: PruneOptions) -> Result<()> {
+    let posts = match fetch_all_posts(client, options.user_id).await {
+        Ok(posts) => posts,
+        Err(error) => {
+            print_error(&format!("Failed to list posts: {}", error));
+            return Ok(());
+        }
+    };
+
+    let candidates = posts
+        .iter()
+        .map(Candidate::from_post)
+        .collect::<Result<Vec<_>>>()?;
+    let selected: Vec<&Candidate> = candidates
+        .iter()
+        .filter(|candidate| options.filter.matches(candidate))
+        .collect();
+
+    if selected.is_empty() {
+        println!(
+            "{}",
+            format!("No posts match the filters ({} checked).", candidates.len()).yellow()
+        );
+        return Ok(());
+    }
+
+    println!(
+        "\n{} ({} of {}):\n",
+        "Matching Posts".bold().underline(),
+        selected.len(),
+        candidates.len()
+    );
+    let rows: Vec<Vec<String>> = selected
+        .iter()
+        .map(|candidate| {
+            vec![
+                candidate.id.clone(),
+                candidate.visibility.clone(),
+                candidate.likes.to_string(),
+                excerpt(&candidate.content, 56),
+                candidate.created_at.format("%Y-%m-%d %H:%M").to_string(),
+            ]
+        })
+        .collect();
+    print_table(
+        vec!["ID", "Visibility", "Likes", "Content", "Created"],
+        rows,
+    );
+
+    if options.dry_run {
+        println!("\n{}", "Dry run: no posts were deleted.".yellow());
+        return Ok(());
+    }
+
+    if !options.yes {
+        if !console::user_attended() {
+            anyhow::bail!(
+                "Refusing to delete without confirmation; pass `--yes` to skip the prompt."
+            );
+        }
+
+        let confirmed = dialoguer::Confirm::new()
+            .with_prompt(format!(
+                "Delete {} post(s)? This cannot be undone",
+                selected.len()
+            ))
+            .default(false)
+            .interact()?;
+        if !confirmed {
+            println!("{}", "Aborted.".yellow());
+            return Ok(());
+        }
+    }
+
+    let results: Vec<(String, Result<()>)> = stream::iter(selected.iter().map(|candidate| {
+        let id = candidate.id.clone();
+        async move {
+            let result = client.delete_post(&id).await;
+            (id, result)
+        }
+    }))
+    .buffer_unordered(options.concurrency.max(1))
+    .collect()
+    .await;
+
+    let failures: Vec<&(String, Result<()>)> = results
+        .iter()
+        .filter(|(_, result)| result.is_err())
+        .collect();
+    let deleted = results.len() - failures.len();
+
+    println!();
+    for (id, result) in &failures {
+        if let Err(error) = result {
+            print_error(&format!("Failed to delete {}: {}", id, error));
+        }
+    }
+
+    // 部分删除失败时以非零状态退出，脚本可以据此重试
+    if !failures.is_empty() {
+        anyhow::bail!(
+            "{} of {} deletion(s) failed ({} post(s) deleted).",
+            failures.len(),
+            results.len(),
+            deleted
+        );
+    }
+    print_success(&format!("Deleted {} post(s).", deleted));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn candidate(content: &str, visibility: &str, likes: u64, day: u32) -> Candidate {
+        Candidate {
+            id: format!("post-{}", day),
+            created_at: Utc.with_ymd_and_hms(2026, 1, day, 0, 0, 0).unwrap(),
+            visibility: visibility.to_string(),
+            likes,
+            content: content.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_empty_filter_is_detected() {
+        assert!(PruneFilter::default().is_empty());
+    }
+
+    #[test]
+    fn test_filter_requires_all_conditions() {
+        let filter = PruneFilter {
+            before: Some(Utc.with_ymd_and_hms(2026, 1, 10, 0, 0, 0).unwrap()),
+            visibility: Some(PostVisibilityArg::Public),
+            max_likes: Some(1),
+            pattern: Some(Regex::new("(?i)^test").unwrap()),
+        };
+
+        assert!(filter.matches(&candidate("TEST run 42", "public", 0, 2)));
+        assert!(!filter.matches(&candidate("TEST run 42", "public", 0, 20)));
+        assert!(!filter.matches(&candidate("TEST run 42", "private", 0, 2)));
+        assert!(!filter.matches(&candidate("TEST run 42", "public", 5, 2)));
+        assert!(!filter.matches(&candidate("real update", "public", 0, 2)));
+    }
+}
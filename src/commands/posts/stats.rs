@@ -0,0 +1,431 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, Utc};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::api::ApiClient;
+use crate::config::Config;
+use crate::models::{PostListQuery, PostResponse};
+use crate::utils::output::{print_error, print_success, print_table, print_warning};
+use crate::utils::state;
+
+/// 互动快照存储文件名
+const STATS_FILE: &str = "post_stats.json";
+
+/// 拉取动态时的每页条数
+const STATS_PAGE_SIZE: i64 = 50;
+
+const SPARK_CHARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// 统计参数
+pub struct StatsOptions {
+    pub user_id: uuid::Uuid,
+    pub record: bool,
+    pub report: bool,
+    pub csv: Option<PathBuf>,
+    pub limit: usize,
+}
+
+/// 单条动态在某一时刻的互动数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PostSample {
+    post_id: String,
+    visibility: String,
+    excerpt: String,
+    likes: u64,
+    comments: u64,
+    shares: u64,
+}
+
+impl PostSample {
+    fn from_post(post: &PostResponse) -> Result<Self> {
+        Ok(Self {
+            post_id: post.id.to_string(),
+            visibility: post.visibility.clone(),
+            excerpt: excerpt(&post.content, 60),
+            likes: u64::try_from(post.like_count)?,
+            comments: u64::try_from(post.comment_count)?,
+            shares: u64::try_from(post.share_count)?,
+        })
+    }
+
+    fn total(&self) -> u64 {
+        self.likes + self.comments + self.shares
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Snapshot {
+    taken_at: DateTime<Utc>,
+    user_id: String,
+    posts: Vec<PostSample>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct StatsStore {
+    snapshots: Vec<Snapshot>,
+}
+
+/// 单条动态在多次快照间的变化
+#[derive(Debug, Serialize)]
+struct PostGrowth {
+    post_id: String,
+    visibility: String,
+    excerpt: String,
+    first: (u64, u64, u64),
+    last: (u64, u64, u64),
+    totals: Vec<u64>,
+}
+
+impl PostGrowth {
+    fn growth(&self) -> i64 {
+        let first = self.first.0 + self.first.1 + self.first.2;
+        let last = self.last.0 + self.last.1 + self.last.2;
+        last as i64 - first as i64
+    }
+}
+
+pub async fn run(
+    client: &ApiClient,
+    config: &Config,
+    options: StatsOptions,
+    format: crate::OutputFormat,
+) -> Result<()> {
+    let mut store: StatsStore = state::load(config, STATS_FILE)?;
+    let user_id = options.user_id.to_string();
+
+    if options.record {
+        let posts = match fetch_all_posts(client, options.user_id).await {
+            Ok(posts) => posts,
+            Err(error) => {
+                print_error(&format!("Failed to list posts: {}", error));
+                return Ok(());
+            }
+        };
+
+        let snapshot = Snapshot {
+            taken_at: Utc::now(),
+            user_id: user_id.clone(),
+            posts: posts
+                .iter()
+                .map(PostSample::from_post)
+                .collect::<Result<Vec<_>>>()?,
+        };
+        let recorded = snapshot.posts.len();
+        store.snapshots.push(snapshot);
+        state::save(config, STATS_FILE, &store)?;
+        print_success(&format!(
+            "Recorded engagement for {} post(s) ({} snapshot(s) stored).",
+            recorded,
+            store
+                .snapshots
+                .iter()
+                .filter(|snapshot| snapshot.user_id == user_id)
+                .count()
+        ));
+    }
+
+    let snapshots: Vec<&Snapshot> = store
+        .snapshots
+        .iter()
+        .filter(|snapshot| snapshot.user_id == user_id)
+        .collect();
+
+    if let Some(path) = &options.csv {
+        write_csv(path, &snapshots)?;
+        print_success(&format!("Exported snapshots to {}", path.display()));
+    }
+
+    if !options.report {
+        return Ok(());
+    }
+
+    if snapshots.is_empty() {
+        println!(
+            "{}",
+            "No snapshots recorded yet. Run `agentlink posts stats --me --record` first.".yellow()
+        );
+        return Ok(());
+    }
+
+    let mut growth = growth_by_post(&snapshots);
+    growth.sort_by(|a, b| b.growth().cmp(&a.growth()));
+
+    match format {
+        crate::OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&growth)?);
+        }
+        crate::OutputFormat::Yaml => {
+            println!("{}", serde_yaml::to_string(&growth)?);
+        }
+        _ => print_report(&snapshots, &growth, options.limit),
+    }
+    Ok(())
+}
+
+/// 翻页拉取用户的全部动态，直到返回不足一页
+///
+/// 服务端忽略分页参数、重复返回同一页时停止并给出警告，避免无限翻页
+pub(crate) async fn fetch_all_posts(
+    client: &ApiClient,
+    user_id: uuid::Uuid,
+) -> Result<Vec<PostResponse>> {
+    let mut posts: Vec<PostResponse> = Vec::new();
+    let mut seen = HashSet::new();
+    for page in 1.. {
+        let batch = client
+            .list_posts(PostListQuery {
+                user_id: Some(user_id),
+                visibility: None,
+                page: Some(page),
+                per_page: Some(STATS_PAGE_SIZE),
+            })
+            .await?;
+        let done = (batch.len() as i64) < STATS_PAGE_SIZE;
+
+        let before = posts.len();
+        posts.extend(batch.into_iter().filter(|post| seen.insert(post.id)));
+        if !done && posts.len() == before {
+            print_warning(&format!(
+                "Page {} of posts repeated earlier results; stopping after {} post(s).",
+                page,
+                posts.len()
+            ));
+            break;
+        }
+        if done {
+            break;
+        }
+    }
+    Ok(posts)
+}
+
+/// 按动态汇总各次快照，快照须按时间顺序排列
+fn growth_by_post(snapshots: &[&Snapshot]) -> Vec<PostGrowth> {
+    let mut by_post: BTreeMap<String, PostGrowth> = BTreeMap::new();
+
+    for snapshot in snapshots {
+        for sample in &snapshot.posts {
+            let counts = (sample.likes, sample.comments, sample.shares);
+            by_post
+                .entry(sample.post_id.clone())
+                .and_modify(|growth| {
+                    growth.last = counts;
+                    growth.totals.push(sample.total());
+                    growth.excerpt = sample.excerpt.clone();
+                    growth.visibility = sample.visibility.clone();
+                })
+                .or_insert_with(|| PostGrowth {
+                    post_id: sample.post_id.clone(),
+                    visibility: sample.visibility.clone(),
+                    excerpt: sample.excerpt.clone(),
+                    first: counts,
+                    last: counts,
+                    totals: vec![sample.total()],
+                });
+        }
+    }
+
+    by_post.into_values().collect()
+}
+
+fn print_report(snapshots: &[&Snapshot], growth: &[PostGrowth], limit: usize) {
+    let (Some(first), Some(last)) = (snapshots.first(), snapshots.last()) else {
+        return;
+    };
+
+    println!("\n{}\n", "Post Engagement Report".bold().underline());
+    println!(
+        "{}",
+        format!(
+            "{} snapshot(s) from {} to {}",
+            snapshots.len(),
+            first
+                .taken_at
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M"),
+            last.taken_at.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+        )
+        .dimmed()
+    );
+    println!();
+
+    let rows: Vec<Vec<String>> = growth
+        .iter()
+        .take(limit)
+        .map(|post| {
+            vec![
+                post.post_id.get(..8).unwrap_or(&post.post_id).to_string(),
+                post.visibility.clone(),
+                post.excerpt.clone(),
+                delta(post.first.0, post.last.0),
+                delta(post.first.1, post.last.1),
+                delta(post.first.2, post.last.2),
+                sparkline(&post.totals),
+            ]
+        })
+        .collect();
+    print_table(
+        vec![
+            "ID",
+            "Visibility",
+            "Content",
+            "Likes",
+            "Comments",
+            "Shares",
+            "Trend",
+        ],
+        rows,
+    );
+
+    // 按可见范围汇总，便于比较不同类型动态的表现
+    let mut by_visibility: BTreeMap<&str, (usize, i64)> = BTreeMap::new();
+    for post in growth {
+        let entry = by_visibility
+            .entry(post.visibility.as_str())
+            .or_insert((0, 0));
+        entry.0 += 1;
+        entry.1 += post.growth();
+    }
+
+    println!("\n{}:\n", "By Visibility".bold());
+    let rows: Vec<Vec<String>> = by_visibility
+        .into_iter()
+        .map(|(visibility, (posts, growth))| {
+            vec![
+                visibility.to_string(),
+                posts.to_string(),
+                format!("{:+}", growth),
+                format!("{:+.1}", growth as f64 / posts as f64),
+            ]
+        })
+        .collect();
+    print_table(vec!["Visibility", "Posts", "Growth", "Avg Growth"], rows);
+}
+
+fn write_csv(path: &Path, snapshots: &[&Snapshot]) -> Result<()> {
+    let mut writer = csv::Writer::from_path(path)
+        .with_context(|| format!("Failed to create CSV file: {:?}", path))?;
+    writer.write_record([
+        "taken_at",
+        "post_id",
+        "visibility",
+        "likes",
+        "comments",
+        "shares",
+        "excerpt",
+    ])?;
+
+    for snapshot in snapshots {
+        for sample in &snapshot.posts {
+            writer.write_record([
+                snapshot.taken_at.to_rfc3339(),
+                sample.post_id.clone(),
+                sample.visibility.clone(),
+                sample.likes.to_string(),
+                sample.comments.to_string(),
+                sample.shares.to_string(),
+                sample.excerpt.clone(),
+            ])?;
+        }
+    }
+
+    writer
+        .flush()
+        .with_context(|| format!("Failed to write CSV file: {:?}", path))?;
+    Ok(())
+}
+
+/// 用 8 级方块字符绘制趋势
+fn sparkline(values: &[u64]) -> String {
+    let (Some(min), Some(max)) = (values.iter().min(), values.iter().max()) else {
+        return String::new();
+    };
+    let range = max - min;
+
+    values
+        .iter()
+        .map(|value| {
+            if range == 0 {
+                SPARK_CHARS[0]
+            } else {
+                let level = (value - min) * (SPARK_CHARS.len() as u64 - 1) / range;
+                SPARK_CHARS[level as usize]
+            }
+        })
+        .collect()
+}
+
+fn delta(first: u64, last: u64) -> String {
+    let change = last as i64 - first as i64;
+    if change == 0 {
+        last.to_string()
+    } else {
+        format!("{} ({:+})", last, change)
+    }
+}
+
+pub(super) fn excerpt(content: &str, max_chars: usize) -> String {
+    let collapsed = content.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= max_chars {
+        collapsed
+    } else {
+        format!(
+            "{}...",
+            collapsed.chars().take(max_chars).collect::<String>()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(post_id: &str, likes: u64) -> PostSample {
+        PostSample {
+            post_id: post_id.to_string(),
+            visibility: "public".to_string(),
+            excerpt: "hello".to_string(),
+            likes,
+            comments: 0,
+            shares: 0,
+        }
+    }
+
+    fn snapshot(posts: Vec<PostSample>) -> Snapshot {
+        Snapshot {
+            taken_at: Utc::now(),
+            user_id: "me".to_string(),
+            posts,
+        }
+    }
+
+    #[test]
+    fn test_sparkline_scales_between_min_and_max() {
+        assert_eq!(sparkline(&[0, 7, 14]), "▁▄█");
+        assert_eq!(sparkline(&[3, 3]), "▁▁");
+        assert_eq!(sparkline(&[]), "");
+    }
+
+    #[test]
+    fn test_growth_by_post_tracks_first_and_last_snapshot() {
+        let first = snapshot(vec![sample("a", 1), sample("b", 5)]);
+        let second = snapshot(vec![sample("a", 4), sample("b", 5), sample("c", 2)]);
+
+        let growth = growth_by_post(&[&first, &second]);
+        let a = growth.iter().find(|post| post.post_id == "a").unwrap();
+        assert_eq!(a.growth(), 3);
+        assert_eq!(a.totals, vec![1, 4]);
+
+        let c = growth.iter().find(|post| post.post_id == "c").unwrap();
+        assert_eq!(c.growth(), 0);
+    }
+
+    #[test]
+    fn test_delta_shows_signed_change() {
+        assert_eq!(delta(2, 5), "5 (+3)");
+        assert_eq!(delta(5, 5), "5");
+    }
+}
@@ -0,0 +1,639 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, Utc};
+use clap::Subcommand;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::api::ApiClient;
+use crate::commands::messages::resolve;
+use crate::config::Config;
+use crate::models::{CreatePostRequest, SendMessageRequest};
+use crate::utils::output::{print_error, print_success, print_table};
+use crate::utils::state;
+use crate::utils::time::{format_duration_short, parse_interval, sleep_or_interrupt};
+
+/// 待发送队列文件名
+const QUEUE_FILE: &str = "send_queue.json";
+
+/// 队列锁文件名，避免 cron 与 `--loop` 同时发送
+const QUEUE_LOCK_FILE: &str = "send_queue.lock";
+
+/// 锁文件超过该时长视为残留，可被接管
+const STALE_LOCK_SECS: u64 = 15 * 60;
+
+/// 等待队列锁的最长时间
+const LOCK_WAIT_SECS: u64 = 60;
+
+/// 首次失败后的重试间隔，之后每次翻倍
+const RETRY_BASE_SECS: i64 = 60;
+
+/// 重试间隔上限
+const RETRY_MAX_SECS: i64 = 60 * 60;
+
+#[derive(Subcommand)]
+pub enum QueueCommands {
+    /// 列出待发送的动态和消息
+    List,
+
+    /// 取消待发送条目（支持 ID 前缀）
+    Cancel { id: String },
+
+    /// 重新排队已标记为失败的条目（支持 ID 前缀）
+    Retry { id: String },
+
+    /// 发送所有已到期的条目
+    Run {
+        /// 持续运行，按间隔检查队列
+        #[arg(long = "loop")]
+        run_loop: bool,
+
+        /// 持续运行时的检查间隔（例如 30s、1m）
+        #[arg(long, default_value = "1m", value_parser = parse_interval)]
+        interval: Duration,
+
+        /// 只列出将要发送的条目，不实际发送
+        #[arg(long)]
+        dry_run: bool,
+
+        /// 连续失败达到该次数后标记为失败，不再自动重试
+        #[arg(long, default_value = "5", value_parser = clap::value_parser!(u32).range(1..))]
+        max_attempts: u32,
+    },
+}
+
+/// 队列中的待发送内容
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum QueuedPayload {
+    Post {
+        request: CreatePostRequest,
+    },
+    Message {
+        /// 会话 ID 或 `@linkid`；linkid 在发送时才解析
+        conversation_id: String,
+        request: SendMessageRequest,
+    },
+}
+
+impl QueuedPayload {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Post { .. } => "post",
+            Self::Message { .. } => "message",
+        }
+    }
+
+    fn target(&self) -> String {
+        match self {
+            Self::Post { request } => request
+                .visibility
+                .clone()
+                .unwrap_or_else(|| "public".to_string()),
+            Self::Message {
+                conversation_id, ..
+            } => conversation_id.clone(),
+        }
+    }
+
+    fn content(&self) -> &str {
+        match self {
+            Self::Post { request } => &request.content,
+            Self::Message { request, .. } => &request.content,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedItem {
+    pub id: String,
+    pub due_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub payload: QueuedPayload,
+    #[serde(default)]
+    pub attempts: u32,
+    #[serde(default)]
+    pub last_error: Option<String>,
+    /// 失败后的下次重试时间
+    #[serde(default)]
+    pub retry_at: Option<DateTime<Utc>>,
+    /// 达到最大尝试次数的时间；失败的条目不再自动发送
+    #[serde(default)]
+    pub failed_at: Option<DateTime<Utc>>,
+}
+
+impl QueuedItem {
+    /// 是否应在 `now` 发送：已到期、不在重试等待中且未标记失败
+    fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.failed_at.is_none() && self.retry_at.unwrap_or(self.due_at) <= now
+    }
+
+    /// 记录一次失败：未达上限时按指数退避安排重试，否则标记为失败
+    fn record_failure(&mut self, error: String, max_attempts: u32, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.last_error = Some(error);
+        if self.attempts >= max_attempts {
+            self.retry_at = None;
+            self.failed_at = Some(now);
+        } else {
+            self.retry_at = Some(now + retry_delay(self.attempts));
+        }
+    }
+}
+
+/// 第 `attempts` 次失败后的等待时间
+fn retry_delay(attempts: u32) -> chrono::Duration {
+    let exponent = attempts.saturating_sub(1).min(16);
+    chrono::Duration::seconds((RETRY_BASE_SECS << exponent).min(RETRY_MAX_SECS))
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SendQueue {
+    items: Vec<QueuedItem>,
+}
+
+impl SendQueue {
+    /// 按 ID 或唯一前缀查找条目下标
+    fn position(&self, id: &str) -> Result<usize> {
+        let matches: Vec<usize> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.id.starts_with(id))
+            .map(|(index, _)| index)
+            .collect();
+
+        match matches.as_slice() {
+            [index] => Ok(*index),
+            [] => anyhow::bail!("No queued item matches `{}`", id),
+            _ => anyhow::bail!(
+                "`{}` matches {} queued items; use a longer ID",
+                id,
+                matches.len()
+            ),
+        }
+    }
+
+    fn due_ids(&self, now: DateTime<Utc>) -> Vec<String> {
+        let mut due: Vec<&QueuedItem> = self.items.iter().filter(|item| item.is_due(now)).collect();
+        due.sort_by_key(|item| item.due_at);
+        due.into_iter().map(|item| item.id.clone()).collect()
+    }
+}
+
+/// 将内容加入待发送队列
+pub async fn enqueue(
+    config: &Config,
+    payload: QueuedPayload,
+    due_at: DateTime<Utc>,
+) -> Result<QueuedItem> {
+    let item = QueuedItem {
+        id: uuid::Uuid::new_v4().to_string(),
+        due_at,
+        created_at: Utc::now(),
+        payload,
+        attempts: 0,
+        last_error: None,
+        retry_at: None,
+        failed_at: None,
+    };
+
+    let _lock = QueueLock::acquire(config).await?;
+    let mut queue: SendQueue = state::load(config, QUEUE_FILE)?;
+    queue.items.push(item.clone());
+    state::save(config, QUEUE_FILE, &queue)?;
+    Ok(item)
+}
+
+/// 打印入队结果
+pub fn print_enqueued(item: &QueuedItem) {
+    print_success(&format!(
+        "Queued {} for {} ({}).",
+        item.payload.kind(),
+        item.due_at.with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+        relative_time(item.due_at, Utc::now())
+    ));
+    println!("{}: {}", "Queue ID".bold(), short_id(&item.id));
+    println!(
+        "{}",
+        "Run `agentlink queue run` (for example from cron) or `agentlink queue run --loop` to send it."
+            .dimmed()
+    );
+}
+
+pub async fn execute(
+    command: QueueCommands,
+    config: &Config,
+    format: crate::OutputFormat,
+) -> Result<()> {
+    match command {
+        QueueCommands::List => {
+            let mut queue: SendQueue = state::load(config, QUEUE_FILE)?;
+            queue.items.sort_by_key(|item| item.due_at);
+
+            match format {
+                crate::OutputFormat::Json => {
+                    println!("{}", serde_json::to_string_pretty(&queue.items)?);
+                }
+                crate::OutputFormat::Yaml => {
+                    println!("{}", serde_yaml::to_string(&queue.items)?);
+                }
+                _ => {
+                    if queue.items.is_empty() {
+                        println!("{}", "The send queue is empty.".yellow());
+                        return Ok(());
+                    }
+
+                    let now = Utc::now();
+                    println!("\n{}:\n", "Send Queue".bold().underline());
+                    let rows: Vec<Vec<String>> = queue
+                        .items
+                        .iter()
+                        .map(|item| {
+                            vec![
+                                short_id(&item.id).to_string(),
+                                item.payload.kind().to_string(),
+                                item.due_at
+                                    .with_timezone(&Local)
+                                    .format("%Y-%m-%d %H:%M")
+                                    .to_string(),
+                                relative_time(item.due_at, now),
+                                item.payload.target(),
+                                truncate(item.payload.content(), 48),
+                                item_status(item, now),
+                            ]
+                        })
+                        .collect();
+                    print_table(
+                        vec!["ID", "Kind", "Due", "When", "Target", "Content", "Status"],
+                        rows,
+                    );
+                }
+            }
+            Ok(())
+        }
+        QueueCommands::Cancel { id } => {
+            let _lock = QueueLock::acquire(config).await?;
+            let mut queue: SendQueue = state::load(config, QUEUE_FILE)?;
+            let index = queue.position(id.trim())?;
+            let item = queue.items.remove(index);
+            state::save(config, QUEUE_FILE, &queue)?;
+            print_success(&format!(
+                "Cancelled queued {} {}.",
+                item.payload.kind(),
+                short_id(&item.id)
+            ));
+            Ok(())
+        }
+        QueueCommands::Retry { id } => {
+            let _lock = QueueLock::acquire(config).await?;
+            let mut queue: SendQueue = state::load(config, QUEUE_FILE)?;
+            let index = queue.position(id.trim())?;
+            let item = &mut queue.items[index];
+            if item.failed_at.is_none() {
+                anyhow::bail!(
+                    "Queued {} {} has not failed; it is retried automatically.",
+                    item.payload.kind(),
+                    short_id(&item.id)
+                );
+            }
+
+            item.attempts = 0;
+            item.retry_at = None;
+            item.failed_at = None;
+            let message = format!(
+                "Re-queued {} {}; it will be sent on the next queue run.",
+                item.payload.kind(),
+                short_id(&item.id)
+            );
+            state::save(config, QUEUE_FILE, &queue)?;
+            print_success(&message);
+            Ok(())
+        }
+        QueueCommands::Run {
+            run_loop,
+            interval,
+            dry_run,
+            max_attempts,
+        } => {
+            ensure_authenticated(config)?;
+            let client = ApiClient::new(config)?;
+
+            if run_loop {
+                println!(
+                    "{}",
+                    format!(
+                        "Sending queued items every {}s. Press Ctrl+C to exit.",
+                        interval.as_secs()
+                    )
+                    .cyan()
+                );
+            }
+
+            loop {
+                let sent = run_due(&client, config, dry_run, max_attempts).await?;
+                if !run_loop {
+                    if sent == 0 {
+                        println!("{}", "Nothing is due.".dimmed());
+                    }
+                    break;
+                }
+
+                if !sleep_or_interrupt(interval).await {
+                    break;
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+/// 发送所有到期条目，返回处理的条目数
+///
+/// 每条条目在持有队列锁期间读取、发送并落盘，多个 `queue run` 不会重复发送同一条
+async fn run_due(
+    client: &ApiClient,
+    config: &Config,
+    dry_run: bool,
+    max_attempts: u32,
+) -> Result<usize> {
+    let queue: SendQueue = state::load(config, QUEUE_FILE)?;
+    let due_ids = queue.due_ids(Utc::now());
+
+    for id in &due_ids {
+        let _lock = QueueLock::acquire(config).await?;
+        // 每条都重新读取队列，期间已发送、已取消或新入队的条目不会被覆盖
+        let mut queue: SendQueue = state::load(config, QUEUE_FILE)?;
+        let Some(item) = queue
+            .items
+            .iter()
+            .find(|queued| &queued.id == id && queued.is_due(Utc::now()))
+            .cloned()
+        else {
+            continue;
+        };
+
+        if dry_run {
+            println!(
+                "{} {} {} → {}: {}",
+                "would send".yellow(),
+                short_id(&item.id),
+                item.payload.kind(),
+                item.payload.target(),
+                truncate(item.payload.content(), 60)
+            );
+            continue;
+        }
+
+        let result = match &item.payload {
+            QueuedPayload::Post { request } => client
+                .create_post(request.clone())
+                .await
+                .map(|post| post.id.to_string()),
+            QueuedPayload::Message {
+                conversation_id,
+                request,
+            } => match resolve::resolve_conversation(client, conversation_id, true).await {
+                Ok(conversation_id) => client
+                    .send_message(&conversation_id, request.clone())
+                    .await
+                    .map(|message| message.id.to_string()),
+                Err(error) => Err(error),
+            },
+        };
+
+        match result {
+            Ok(created_id) => {
+                queue.items.retain(|queued| queued.id != item.id);
+                print_success(&format!(
+                    "Sent queued {} {} ({}).",
+                    item.payload.kind(),
+                    short_id(&item.id),
+                    created_id
+                ));
+            }
+            Err(error) => {
+                let error = format!("{:#}", error);
+                if let Some(queued) = queue.items.iter_mut().find(|queued| queued.id == item.id) {
+                    queued.record_failure(error.clone(), max_attempts, Utc::now());
+                    let next = match queued.retry_at {
+                        Some(retry_at) => {
+                            format!("retrying {}", relative_time(retry_at, Utc::now()))
+                        }
+                        None => format!(
+                            "marked as failed after {} attempt(s); use `agentlink queue retry {}`",
+                            queued.attempts,
+                            short_id(&queued.id)
+                        ),
+                    };
+                    print_error(&format!(
+                        "Failed to send queued {} {}: {} ({})",
+                        item.payload.kind(),
+                        short_id(&item.id),
+                        error,
+                        next
+                    ));
+                }
+            }
+        }
+
+        // 每条处理完立即落盘，中途退出也不会重复发送
+        state::save(config, QUEUE_FILE, &queue)?;
+    }
+
+    Ok(due_ids.len())
+}
+
+/// 基于锁文件的进程间互斥
+struct QueueLock {
+    path: PathBuf,
+}
+
+impl QueueLock {
+    /// 获取锁，被占用时异步等待，超过 `LOCK_WAIT_SECS` 仍未释放则报错
+    async fn acquire(config: &Config) -> Result<Self> {
+        let deadline = std::time::Instant::now() + Duration::from_secs(LOCK_WAIT_SECS);
+        loop {
+            if let Some(lock) = Self::try_acquire(config)? {
+                return Ok(lock);
+            }
+            if std::time::Instant::now() >= deadline {
+                anyhow::bail!(
+                    "The send queue is locked by another agentlink process; try again later."
+                );
+            }
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+    }
+
+    /// 尝试获取锁，被占用时返回 None；残留的过期锁会被接管
+    fn try_acquire(config: &Config) -> Result<Option<Self>> {
+        let path = config.state_file_path(QUEUE_LOCK_FILE)?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let stale = std::fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age.as_secs() > STALE_LOCK_SECS);
+        if stale {
+            let _ = std::fs::remove_file(&path);
+        }
+
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(_) => Ok(Some(Self { path })),
+            Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => Ok(None),
+            Err(error) => {
+                Err(error).with_context(|| format!("Failed to create lock file: {:?}", path))
+            }
+        }
+    }
+}
+
+impl Drop for QueueLock {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+fn ensure_authenticated(config: &Config) -> Result<()> {
+    if config.has_api_key() {
+        Ok(())
+    } else {
+        anyhow::bail!(
+            "No agent API key configured. Run `agentlink api-key set <sk_...>` or pass `--api-key`."
+        )
+    }
+}
+
+/// 列表中的状态列
+fn item_status(item: &QueuedItem, now: DateTime<Utc>) -> String {
+    let Some(error) = &item.last_error else {
+        return "pending".to_string();
+    };
+
+    let error = truncate(error, 40);
+    match (item.failed_at, item.retry_at) {
+        (Some(_), _) => format!("failed after {}: {}", item.attempts, error),
+        (None, Some(retry_at)) => format!(
+            "retry {} ({} failed): {}",
+            relative_time(retry_at, now),
+            item.attempts,
+            error
+        ),
+        (None, None) => format!("{} failed: {}", item.attempts, error),
+    }
+}
+
+fn relative_time(time: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    if time <= now {
+        "due now".to_string()
+    } else {
+        format!("in {}", format_duration_short(time - now))
+    }
+}
+
+fn short_id(id: &str) -> &str {
+    id.get(..8).unwrap_or(id)
+}
+
+fn truncate(value: &str, max_chars: usize) -> String {
+    let value = value.replace('\n', " ");
+    if value.chars().count() <= max_chars {
+        value
+    } else {
+        format!("{}...", value.chars().take(max_chars).collect::<String>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post_item(id: &str, due_at: DateTime<Utc>) -> QueuedItem {
+        QueuedItem {
+            id: id.to_string(),
+            due_at,
+            created_at: due_at,
+            payload: QueuedPayload::Post {
+                request: CreatePostRequest {
+                    content: "status update".to_string(),
+                    media_urls: None,
+                    visibility: Some("public".to_string()),
+                },
+            },
+            attempts: 0,
+            last_error: None,
+            retry_at: None,
+            failed_at: None,
+        }
+    }
+
+    #[test]
+    fn test_due_ids_returns_due_items_oldest_first() {
+        let now = Utc::now();
+        let queue = SendQueue {
+            items: vec![
+                post_item("b", now - chrono::Duration::minutes(1)),
+                post_item("c", now + chrono::Duration::hours(1)),
+                post_item("a", now - chrono::Duration::hours(1)),
+            ],
+        };
+
+        assert_eq!(queue.due_ids(now), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_record_failure_backs_off_then_marks_failed() {
+        let now = Utc::now();
+        let mut item = post_item("a", now);
+
+        item.record_failure("timeout".to_string(), 3, now);
+        assert_eq!(item.retry_at, Some(now + chrono::Duration::seconds(60)));
+        assert!(!item.is_due(now));
+        assert!(item.is_due(now + chrono::Duration::seconds(60)));
+
+        item.record_failure("timeout".to_string(), 3, now);
+        assert_eq!(item.retry_at, Some(now + chrono::Duration::seconds(120)));
+
+        item.record_failure("timeout".to_string(), 3, now);
+        assert_eq!(item.attempts, 3);
+        assert_eq!(item.failed_at, Some(now));
+        assert!(!item.is_due(now + chrono::Duration::days(1)));
+    }
+
+    #[test]
+    fn test_retry_delay_is_capped() {
+        assert_eq!(retry_delay(1), chrono::Duration::seconds(60));
+        assert_eq!(retry_delay(3), chrono::Duration::seconds(240));
+        assert_eq!(retry_delay(40), chrono::Duration::seconds(RETRY_MAX_SECS));
+    }
+
+    #[test]
+    fn test_position_matches_unique_prefix() {
+        let now = Utc::now();
+        let queue = SendQueue {
+            items: vec![post_item("abc123", now), post_item("abd456", now)],
+        };
+
+        assert_eq!(queue.position("abc").unwrap(), 0);
+        assert!(queue.position("ab").is_err());
+        assert!(queue.position("zzz").is_err());
+    }
+
+    #[test]
+    fn test_queue_round_trips_through_json() {
+        let queue = SendQueue {
+            items: vec![post_item("abc", Utc::now())],
+        };
+        let json = serde_json::to_string(&queue).unwrap();
+        assert!(json.contains("\"kind\":\"post\""));
+
+        let restored: SendQueue = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.items.len(), 1);
+    }
+}
@@ -0,0 +1,119 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use clap::Args;
+use colored::Colorize;
+
+use crate::commands::index::store::{
+    to_fts_expression, DocKind, SearchHit, SearchIndex, SearchQuery,
+};
+use crate::config::Config;
+use crate::utils::time::parse_datetime;
+
+#[derive(Args, Debug)]
+pub struct SearchArgs {
+    /// 搜索词；多个词需同时命中，词尾加 `*` 表示前缀匹配
+    pub query: String,
+
+    /// 只搜索指定类型
+    #[arg(long = "type", value_enum)]
+    pub kind: Option<DocKind>,
+
+    /// 只搜索指定会话中的消息
+    #[arg(long)]
+    pub conversation: Option<String>,
+
+    /// 起始时间（例如 2026-01-01、RFC 3339）
+    #[arg(long, value_parser = parse_datetime)]
+    pub since: Option<DateTime<Utc>>,
+
+    /// 截止时间（不含）
+    #[arg(long, value_parser = parse_datetime)]
+    pub until: Option<DateTime<Utc>>,
+
+    /// 最多显示的结果数
+    #[arg(long, default_value = "20")]
+    pub limit: usize,
+
+    /// 按 FTS5 语法解析搜索词（支持 OR、NOT、短语和列过滤）
+    #[arg(long)]
+    pub raw: bool,
+}
+
+pub fn execute(args: SearchArgs, config: &Config, format: crate::OutputFormat) -> Result<()> {
+    if args.query.trim().is_empty() {
+        anyhow::bail!("The search query is empty; pass at least one search term.");
+    }
+
+    let index = SearchIndex::open_default(config)?;
+    let hits = index.search(&SearchQuery {
+        expression: if args.raw {
+            args.query
+        } else {
+            to_fts_expression(&args.query)
+        },
+        kind: args.kind,
+        conversation_id: args.conversation,
+        since: args.since,
+        until: args.until,
+        limit: args.limit,
+    })?;
+
+    match format {
+        crate::OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&hits)?);
+        }
+        crate::OutputFormat::Yaml => {
+            println!("{}", serde_yaml::to_string(&hits)?);
+        }
+        _ => {
+            if hits.is_empty() {
+                println!(
+                    "{}",
+                    "No results. Run `agentlink index sync` to refresh the local index.".yellow()
+                );
+                return Ok(());
+            }
+
+            println!(
+                "\n{} ({}):\n",
+                "Search Results".bold().underline(),
+                hits.len()
+            );
+            for (position, hit) in hits.iter().enumerate() {
+                print_hit(position + 1, hit);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_hit(position: usize, hit: &SearchHit) {
+    let context = match (hit.title.is_empty(), hit.author.is_empty()) {
+        (false, false) if hit.kind != "task" => format!("{} · {}", hit.title, hit.author),
+        (false, _) => hit.title.clone(),
+        (true, false) => hit.author.clone(),
+        (true, true) => String::new(),
+    };
+
+    println!(
+        "{}. {} {} {}",
+        position,
+        format!("[{}]", hit.kind).cyan(),
+        context.bold(),
+        hit.created_at.get(..10).unwrap_or(&hit.created_at).dimmed()
+    );
+    println!("   {}", hit.snippet.replace('\n', " "));
+    println!("   {}\n", format!("→ {}", open_command(hit)).dimmed());
+}
+
+/// 打开该结果的命令
+fn open_command(hit: &SearchHit) -> String {
+    match (hit.kind.as_str(), &hit.conversation_id) {
+        ("message", Some(conversation_id)) => {
+            format!("agentlink messages show {}", conversation_id)
+        }
+        ("post", _) => format!("agentlink posts show {}", hit.id),
+        ("task", _) => format!("agentlink tasks show {}", hit.id),
+        _ => hit.id.clone(),
+    }
+}
@@ -0,0 +1,367 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use clap::Subcommand;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+use crate::api::ApiClient;
+use crate::config::Config;
+use crate::models::Skill;
+use crate::utils::output::{print_error, print_table};
+use crate::utils::state;
+
+/// 技能目录缓存文件名
+const SKILL_CACHE_FILE: &str = "skills_cache.json";
+
+/// 技能目录缓存有效期（小时）
+const SKILL_CACHE_TTL_HOURS: i64 = 24;
+
+#[derive(Subcommand)]
+pub enum SkillCommands {
+    /// 按分类列出技能目录
+    List {
+        /// 仅显示指定分类
+        #[arg(short, long)]
+        category: Option<String>,
+
+        /// 忽略本地缓存，重新拉取技能目录
+        #[arg(long)]
+        refresh: bool,
+    },
+
+    /// 按名称或分类搜索技能
+    Search {
+        term: String,
+
+        /// 忽略本地缓存，重新拉取技能目录
+        #[arg(long)]
+        refresh: bool,
+    },
+
+    /// 查看技能详情（支持技能 ID 或名称）
+    Show {
+        id: String,
+
+        /// 忽略本地缓存，重新拉取技能目录
+        #[arg(long)]
+        refresh: bool,
+    },
+}
+
+/// 磁盘上的技能目录缓存
+#[derive(Debug, Serialize, Deserialize)]
+struct SkillCache {
+    server_url: String,
+    fetched_at: DateTime<Utc>,
+    skills: Vec<Skill>,
+}
+
+impl SkillCache {
+    fn is_fresh(&self, server_url: &str, now: DateTime<Utc>) -> bool {
+        self.server_url == server_url
+            && now - self.fetched_at < Duration::hours(SKILL_CACHE_TTL_HOURS)
+    }
+
+    /// 读取缓存；文件缺失或损坏时视为没有缓存
+    fn read(config: &Config) -> Option<Self> {
+        state::load::<Option<Self>>(config, SKILL_CACHE_FILE)
+            .ok()
+            .flatten()
+    }
+
+    fn write(&self, config: &Config) -> Result<()> {
+        state::save(config, SKILL_CACHE_FILE, self)
+    }
+}
+
+/// 带本地缓存的技能目录
+pub struct SkillCatalog {
+    skills: Vec<Skill>,
+    fetched_at: DateTime<Utc>,
+}
+
+impl SkillCatalog {
+    /// 加载技能目录：缓存未过期时直接复用，否则从服务端拉取并写回缓存
+    pub async fn load(client: &ApiClient, config: &Config, refresh: bool) -> Result<Self> {
+        if !refresh {
+            if let Some(cache) = SkillCache::read(config) {
+                if cache.is_fresh(&config.server_url, Utc::now()) {
+                    return Ok(Self {
+                        skills: cache.skills,
+                        fetched_at: cache.fetched_at,
+                    });
+                }
+            }
+        }
+
+        let skills = client.list_skills().await?;
+        let cache = SkillCache {
+            server_url: config.server_url.clone(),
+            fetched_at: Utc::now(),
+            skills,
+        };
+
+        // 缓存写入失败不影响本次使用
+        if let Err(error) = cache.write(config) {
+            tracing::debug!("Failed to cache skill catalog: {}", error);
+        }
+
+        Ok(Self {
+            skills: cache.skills,
+            fetched_at: cache.fetched_at,
+        })
+    }
+
+    pub fn skills(&self) -> &[Skill] {
+        &self.skills
+    }
+
+    /// 按 ID 或名称（忽略大小写）查找技能
+    pub fn find(&self, id_or_name: &str) -> Option<&Skill> {
+        let needle = id_or_name.trim();
+        self.skills
+            .iter()
+            .find(|skill| skill.id.to_string() == needle)
+            .or_else(|| {
+                self.skills
+                    .iter()
+                    .find(|skill| skill.name.eq_ignore_ascii_case(needle))
+            })
+    }
+
+    /// 将技能名称或 ID 列表解析为技能 ID，任一无法识别时返回错误并给出候选
+    pub fn resolve_ids(&self, names: &[String]) -> Result<Vec<Uuid>> {
+        names
+            .iter()
+            .map(|name| match self.find(name) {
+                Some(skill) => Ok(skill.id),
+                None => {
+                    let suggestions: Vec<&str> = self
+                        .search(name)
+                        .iter()
+                        .take(5)
+                        .map(|skill| skill.name.as_str())
+                        .collect();
+                    if suggestions.is_empty() {
+                        anyhow::bail!("Unknown skill `{}`", name)
+                    } else {
+                        anyhow::bail!(
+                            "Unknown skill `{}`. Did you mean: {}?",
+                            name,
+                            suggestions.join(", ")
+                        )
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// 按名称或分类模糊搜索技能
+    pub fn search(&self, term: &str) -> Vec<&Skill> {
+        let term = term.trim().to_lowercase();
+        self.skills
+            .iter()
+            .filter(|skill| {
+                skill.name.to_lowercase().contains(&term)
+                    || skill.category.to_string().to_lowercase().contains(&term)
+            })
+            .collect()
+    }
+}
+
+pub async fn execute(
+    command: SkillCommands,
+    config: &Config,
+    format: crate::OutputFormat,
+) -> Result<()> {
+    let client = ApiClient::new(config)?;
+
+    match command {
+        SkillCommands::List { category, refresh } => {
+            let catalog = match SkillCatalog::load(&client, config, refresh).await {
+                Ok(catalog) => catalog,
+                Err(error) => {
+                    print_error(&format!("Failed to load skills: {}", error));
+                    return Ok(());
+                }
+            };
+
+            let skills: Vec<&Skill> = catalog
+                .skills()
+                .iter()
+                .filter(|skill| {
+                    category.as_deref().map_or(true, |category| {
+                        skill.category.to_string().eq_ignore_ascii_case(category)
+                    })
+                })
+                .collect();
+
+            if skills.is_empty() {
+                println!("{}", "No skills found.".yellow());
+                return Ok(());
+            }
+
+            match format {
+                crate::OutputFormat::Json => {
+                    println!("{}", serde_json::to_string_pretty(&skills)?);
+                }
+                crate::OutputFormat::Yaml => {
+                    println!("{}", serde_yaml::to_string(&skills)?);
+                }
+                _ => {
+                    println!("\n{}:", "Skill Catalog".bold().underline());
+                    print_grouped(&skills);
+                    print_cache_hint(&catalog);
+                }
+            }
+            Ok(())
+        }
+        SkillCommands::Search { term, refresh } => {
+            let catalog = match SkillCatalog::load(&client, config, refresh).await {
+                Ok(catalog) => catalog,
+                Err(error) => {
+                    print_error(&format!("Failed to load skills: {}", error));
+                    return Ok(());
+                }
+            };
+
+            let skills = catalog.search(&term);
+            if skills.is_empty() {
+                println!("{}", format!("No skills matching `{}`.", term).yellow());
+                return Ok(());
+            }
+
+            match format {
+                crate::OutputFormat::Json => {
+                    println!("{}", serde_json::to_string_pretty(&skills)?);
+                }
+                crate::OutputFormat::Yaml => {
+                    println!("{}", serde_yaml::to_string(&skills)?);
+                }
+                _ => {
+                    println!("\n{} `{}`:", "Skills matching".bold().underline(), term);
+                    print_grouped(&skills);
+                    print_cache_hint(&catalog);
+                }
+            }
+            Ok(())
+        }
+        SkillCommands::Show { id, refresh } => {
+            let catalog = match SkillCatalog::load(&client, config, refresh).await {
+                Ok(catalog) => catalog,
+                Err(error) => {
+                    print_error(&format!("Failed to load skills: {}", error));
+                    return Ok(());
+                }
+            };
+
+            let Some(skill) = catalog.find(&id) else {
+                print_error(&format!("Skill not found: {}", id));
+                return Ok(());
+            };
+
+            match format {
+                crate::OutputFormat::Json => {
+                    println!("{}", serde_json::to_string_pretty(skill)?);
+                }
+                crate::OutputFormat::Yaml => {
+                    println!("{}", serde_yaml::to_string(skill)?);
+                }
+                _ => {
+                    println!("\n{}", "Skill Details".bold().underline());
+                    println!();
+                    println!("{}: {}", "ID".bold(), skill.id);
+                    println!("{}: {}", "Name".bold(), skill.name);
+                    println!("{}: {}", "Category".bold(), skill.category);
+
+                    let related: Vec<&Skill> = catalog
+                        .skills()
+                        .iter()
+                        .filter(|other| {
+                            other.id != skill.id
+                                && other.category.to_string() == skill.category.to_string()
+                        })
+                        .collect();
+                    if !related.is_empty() {
+                        println!();
+                        println!("{}:", "Same Category".bold());
+                        for other in related {
+                            println!("  • {}", other.name);
+                        }
+                    }
+                    println!();
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+fn print_grouped(skills: &[&Skill]) {
+    let mut groups: BTreeMap<String, Vec<&Skill>> = BTreeMap::new();
+    for skill in skills {
+        groups
+            .entry(skill.category.to_string())
+            .or_default()
+            .push(skill);
+    }
+
+    for (category, mut skills) in groups {
+        skills.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+
+        println!("\n{} ({})", category.cyan().bold(), skills.len());
+        let rows: Vec<Vec<String>> = skills
+            .iter()
+            .map(|skill| vec![skill.name.clone(), skill.id.to_string()])
+            .collect();
+        print_table(vec!["Name", "ID"], rows);
+    }
+}
+
+fn print_cache_hint(catalog: &SkillCatalog) {
+    println!(
+        "\n{}",
+        format!(
+            "{} skill(s), catalog fetched {}. Use --refresh to update.",
+            catalog.skills().len(),
+            catalog.fetched_at.format("%Y-%m-%d %H:%M UTC")
+        )
+        .dimmed()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache_fetched_at(fetched_at: DateTime<Utc>) -> SkillCache {
+        SkillCache {
+            server_url: "https://beta-api.agentlink.chat/".to_string(),
+            fetched_at,
+            skills: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_skill_cache_is_fresh_within_ttl() {
+        let now = Utc::now();
+        let cache = cache_fetched_at(now - Duration::hours(1));
+        assert!(cache.is_fresh("https://beta-api.agentlink.chat/", now));
+    }
+
+    #[test]
+    fn test_skill_cache_expires_after_ttl() {
+        let now = Utc::now();
+        let cache = cache_fetched_at(now - Duration::hours(SKILL_CACHE_TTL_HOURS + 1));
+        assert!(!cache.is_fresh("https://beta-api.agentlink.chat/", now));
+    }
+
+    #[test]
+    fn test_skill_cache_is_scoped_to_server() {
+        let now = Utc::now();
+        let cache = cache_fetched_at(now);
+        assert!(!cache.is_fresh("https://api.example.com/", now));
+    }
+}
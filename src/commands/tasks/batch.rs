@@ -0,0 +1,391 @@
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use colored::Colorize;
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::api::ApiClient;
+use crate::utils::output::{print_success, print_table};
+
+/// 批量申请输入格式
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum BatchInputFormat {
+    Csv,
+    Ndjson,
+}
+
+impl BatchInputFormat {
+    /// 根据文件扩展名推断输入格式
+    fn infer(input: &str) -> Option<Self> {
+        let extension = Path::new(input)
+            .extension()
+            .and_then(|value| value.to_str())?
+            .to_ascii_lowercase();
+
+        match extension.as_str() {
+            "csv" => Some(Self::Csv),
+            "ndjson" | "jsonl" | "json" => Some(Self::Ndjson),
+            _ => None,
+        }
+    }
+}
+
+/// 单条批量申请
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchApplyEntry {
+    pub task_id: String,
+
+    #[serde(default, alias = "proposed_budget")]
+    pub budget: Option<f64>,
+
+    #[serde(default, alias = "estimated_days")]
+    pub days: Option<i32>,
+
+    #[serde(default)]
+    pub cover_letter: Option<String>,
+}
+
+/// 单条批量申请结果
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchApplyResult {
+    pub task_id: String,
+    pub success: bool,
+    pub application_id: Option<String>,
+    pub status: Option<String>,
+    pub error: Option<String>,
+}
+
+/// 批量申请参数
+pub struct BatchApplyOptions {
+    pub input: String,
+    pub input_format: Option<BatchInputFormat>,
+    pub cover_letter: Option<String>,
+    pub concurrency: usize,
+    pub limit: Option<usize>,
+    pub output: Option<PathBuf>,
+    pub dry_run: bool,
+}
+
+/// 执行批量申请
+pub async fn run(
+    client: &ApiClient,
+    options: BatchApplyOptions,
+    format: crate::OutputFormat,
+) -> Result<()> {
+    let input_format = options
+        .input_format
+        .or_else(|| BatchInputFormat::infer(&options.input))
+        .unwrap_or(BatchInputFormat::Ndjson);
+
+    let content = read_input(&options.input)?;
+    let mut entries = parse_entries(&content, input_format)?;
+
+    let mut seen = HashSet::new();
+    entries.retain(|entry| {
+        let fresh = seen.insert(entry.task_id.clone());
+        if !fresh {
+            eprintln!(
+                "{}",
+                format!("! Skipping duplicate task {}", entry.task_id).yellow()
+            );
+        }
+        fresh
+    });
+
+    if let Some(limit) = options.limit {
+        entries.truncate(limit);
+    }
+
+    for entry in &mut entries {
+        if entry.cover_letter.is_none() {
+            entry.cover_letter = options.cover_letter.clone();
+        }
+    }
+
+    if entries.is_empty() {
+        println!("{}", "No tasks to apply to.".yellow());
+        return Ok(());
+    }
+
+    if options.dry_run {
+        print_dry_run(&entries);
+        return Ok(());
+    }
+
+    // 进度提示写到标准错误，保证 JSON/YAML 输出可以直接解析
+    let total = entries.len();
+    eprintln!(
+        "{}",
+        format!(
+            "Submitting {} application(s) with concurrency {}...",
+            total, options.concurrency
+        )
+        .dimmed()
+    );
+
+    let results: Vec<BatchApplyResult> = stream::iter(entries)
+        .map(|entry| apply_one(client, entry))
+        .buffered(options.concurrency.max(1))
+        .collect()
+        .await;
+
+    if let Some(path) = &options.output {
+        let content = serde_json::to_string_pretty(&results)?;
+        std::fs::write(path, content)
+            .with_context(|| format!("Failed to write results to {:?}", path))?;
+    }
+
+    match format {
+        crate::OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&results)?);
+        }
+        crate::OutputFormat::Yaml => {
+            println!("{}", serde_yaml::to_string(&results)?);
+        }
+        _ => print_report(&results),
+    }
+
+    if let Some(path) = &options.output {
+        if matches!(
+            format,
+            crate::OutputFormat::Table | crate::OutputFormat::Plain
+        ) {
+            print_success(&format!("Results written to {}", path.display()));
+        }
+    }
+
+    let failed = results.iter().filter(|result| !result.success).count();
+    if failed > 0 {
+        anyhow::bail!("{} of {} application(s) failed.", failed, total);
+    }
+    Ok(())
+}
+
+async fn apply_one(client: &ApiClient, entry: BatchApplyEntry) -> BatchApplyResult {
+    let body = super::application_request(entry.cover_letter, entry.budget, entry.days);
+
+    match client.apply_to_task(&entry.task_id, body).await {
+        Ok(application) => BatchApplyResult {
+            task_id: entry.task_id,
+            success: true,
+            application_id: Some(application.id.to_string()),
+            status: Some(format!("{:?}", application.status).to_lowercase()),
+            error: None,
+        },
+        Err(error) => BatchApplyResult {
+            task_id: entry.task_id,
+            success: false,
+            application_id: None,
+            status: None,
+            error: Some(error.to_string()),
+        },
+    }
+}
+
+fn read_input(input: &str) -> Result<String> {
+    if input == "-" {
+        let mut content = String::new();
+        std::io::stdin()
+            .read_to_string(&mut content)
+            .context("Failed to read batch input from stdin")?;
+        Ok(content)
+    } else {
+        std::fs::read_to_string(input)
+            .with_context(|| format!("Failed to read batch input file: {}", input))
+    }
+}
+
+/// 解析批量申请输入
+pub fn parse_entries(content: &str, format: BatchInputFormat) -> Result<Vec<BatchApplyEntry>> {
+    let entries = match format {
+        BatchInputFormat::Csv => parse_csv(content)?,
+        BatchInputFormat::Ndjson => parse_ndjson(content)?,
+    };
+
+    for (index, entry) in entries.iter().enumerate() {
+        if entry.task_id.trim().is_empty() {
+            anyhow::bail!("Entry {} is missing `task_id`", index + 1);
+        }
+    }
+
+    Ok(entries)
+}
+
+fn parse_csv(content: &str) -> Result<Vec<BatchApplyEntry>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .comment(Some(b'#'))
+        .from_reader(content.as_bytes());
+
+    reader
+        .deserialize::<BatchApplyEntry>()
+        .enumerate()
+        .map(|(index, record)| {
+            record.with_context(|| format!("Invalid CSV record on row {}", index + 2))
+        })
+        .collect()
+}
+
+fn parse_ndjson(content: &str) -> Result<Vec<BatchApplyEntry>> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            let line = line.trim();
+            !line.is_empty() && !line.starts_with('#')
+        })
+        .map(|(index, line)| {
+            let value: serde_json::Value = serde_json::from_str(line)
+                .with_context(|| format!("Invalid JSON on line {}", index + 1))?;
+
+            // 允许每行仅写一个任务 ID 字符串
+            match value {
+                serde_json::Value::String(task_id) => Ok(BatchApplyEntry {
+                    task_id,
+                    budget: None,
+                    days: None,
+                    cover_letter: None,
+                }),
+                value => serde_json::from_value(value)
+                    .with_context(|| format!("Invalid entry on line {}", index + 1)),
+            }
+        })
+        .collect()
+}
+
+fn print_dry_run(entries: &[BatchApplyEntry]) {
+    println!(
+        "\n{} ({} task(s)):\n",
+        "Dry Run - Applications".bold().underline(),
+        entries.len()
+    );
+
+    let rows: Vec<Vec<String>> = entries
+        .iter()
+        .map(|entry| {
+            vec![
+                entry.task_id.clone(),
+                entry
+                    .budget
+                    .map(|value| value.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                entry
+                    .days
+                    .map(|value| value.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                entry
+                    .cover_letter
+                    .as_deref()
+                    .map(|value| truncate(value, 48))
+                    .unwrap_or_else(|| "-".to_string()),
+            ]
+        })
+        .collect();
+
+    print_table(vec!["Task ID", "Budget", "Days", "Cover Letter"], rows);
+    println!("\n{}", "No applications were submitted.".dimmed());
+}
+
+fn print_report(results: &[BatchApplyResult]) {
+    println!("\n{}:\n", "Batch Apply Report".bold().underline());
+
+    let rows: Vec<Vec<String>> = results
+        .iter()
+        .map(|result| {
+            let (outcome, detail) = if result.success {
+                (
+                    "✓".green().to_string(),
+                    format!(
+                        "{} ({})",
+                        result.application_id.as_deref().unwrap_or("-"),
+                        result.status.as_deref().unwrap_or("-")
+                    ),
+                )
+            } else {
+                (
+                    "✗".red().to_string(),
+                    result.error.clone().unwrap_or_default(),
+                )
+            };
+
+            vec![result.task_id.clone(), outcome, detail]
+        })
+        .collect();
+
+    print_table(vec!["Task ID", "", "Application / Error"], rows);
+
+    let succeeded = results.iter().filter(|result| result.success).count();
+    let failed = results.len() - succeeded;
+    println!(
+        "\n{}: {}  {}: {}",
+        "Succeeded".green().bold(),
+        succeeded,
+        "Failed".red().bold(),
+        failed
+    );
+}
+
+fn truncate(value: &str, max_chars: usize) -> String {
+    if value.chars().count() <= max_chars {
+        value.to_string()
+    } else {
+        format!("{}...", value.chars().take(max_chars).collect::<String>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_entries_with_optional_columns() {
+        let content = "task_id,budget,days,cover_letter\n\
+                       550e8400-e29b-41d4-a716-446655440000,120.5,3,Happy to help\n\
+                       550e8400-e29b-41d4-a716-446655440001,,,\n";
+
+        let entries = parse_entries(content, BatchInputFormat::Csv).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].budget, Some(120.5));
+        assert_eq!(entries[0].days, Some(3));
+        assert_eq!(entries[0].cover_letter.as_deref(), Some("Happy to help"));
+        assert_eq!(entries[1].budget, None);
+        assert_eq!(entries[1].days, None);
+    }
+
+    #[test]
+    fn test_parse_ndjson_accepts_objects_and_bare_ids() {
+        let content = r#"
+{"task_id": "550e8400-e29b-41d4-a716-446655440000", "budget": 80, "days": 2}
+# comment lines are ignored
+"550e8400-e29b-41d4-a716-446655440001"
+"#;
+
+        let entries = parse_entries(content, BatchInputFormat::Ndjson).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].budget, Some(80.0));
+        assert_eq!(entries[0].days, Some(2));
+        assert_eq!(entries[1].task_id, "550e8400-e29b-41d4-a716-446655440001");
+    }
+
+    #[test]
+    fn test_parse_entries_rejects_missing_task_id() {
+        let error = parse_entries(r#"{"task_id": " "}"#, BatchInputFormat::Ndjson).unwrap_err();
+        assert!(error.to_string().contains("missing `task_id`"));
+    }
+
+    #[test]
+    fn test_infer_input_format_from_extension() {
+        assert_eq!(
+            BatchInputFormat::infer("tasks.CSV"),
+            Some(BatchInputFormat::Csv)
+        );
+        assert_eq!(
+            BatchInputFormat::infer("tasks.jsonl"),
+            Some(BatchInputFormat::Ndjson)
+        );
+        assert_eq!(BatchInputFormat::infer("-"), None);
+    }
+}
@@ -0,0 +1,250 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use console::{pad_str, truncate_str, Alignment, Term};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use super::{deadline_urgency, format_budget, format_countdown, DeadlineUrgency};
+use crate::api::ApiClient;
+use crate::models::{TaskResponse, TaskStatus};
+use crate::utils::output::print_error;
+use crate::utils::time::sleep_or_interrupt;
+
+/// 每列最小宽度；终端过窄时列会换行显示
+const MIN_COLUMN_WIDTH: usize = 26;
+
+/// 列之间的间隔
+const COLUMN_GAP: &str = "  ";
+
+/// 非终端环境下的默认宽度
+const DEFAULT_TERMINAL_WIDTH: usize = 120;
+
+/// 看板参数
+pub struct BoardOptions {
+    pub watch: bool,
+    pub interval: Duration,
+    pub soon: chrono::Duration,
+}
+
+pub async fn run(
+    client: &ApiClient,
+    options: BoardOptions,
+    format: crate::OutputFormat,
+) -> Result<()> {
+    let term = Term::stdout();
+
+    loop {
+        match client.get_my_tasks().await {
+            Ok(response) => {
+                let now = Utc::now();
+                let columns = group_by_status(&response.tasks);
+
+                match format {
+                    crate::OutputFormat::Json => {
+                        println!("{}", serde_json::to_string_pretty(&to_map(&columns))?);
+                    }
+                    crate::OutputFormat::Yaml => {
+                        println!("{}", serde_yaml::to_string(&to_map(&columns))?);
+                    }
+                    _ => {
+                        if options.watch {
+                            term.clear_screen()?;
+                        }
+
+                        let width = term
+                            .size_checked()
+                            .map(|(_, cols)| cols as usize)
+                            .unwrap_or(DEFAULT_TERMINAL_WIDTH);
+
+                        println!("\n{}\n", "Task Board".bold().underline());
+                        if columns.is_empty() {
+                            println!("{}", "You have no tasks.".yellow());
+                        } else {
+                            print_board(&columns, width, now, options.soon);
+                        }
+
+                        if options.watch {
+                            println!(
+                                "\n{}",
+                                format!(
+                                    "Updated {} · refreshing every {}s · Ctrl+C to exit",
+                                    chrono::Local::now().format("%H:%M:%S"),
+                                    options.interval.as_secs()
+                                )
+                                .dimmed()
+                            );
+                        }
+                    }
+                }
+            }
+            Err(error) => {
+                print_error(&format!("Failed to get my tasks: {}", error));
+            }
+        }
+
+        if !options.watch || !sleep_or_interrupt(options.interval).await {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// 状态对应的列：(展示顺序, 列名)
+fn status_column(status: &TaskStatus) -> (u8, &'static str) {
+    match status {
+        TaskStatus::Open => (0, "Open"),
+        TaskStatus::InProgress => (1, "In Progress"),
+        TaskStatus::Completed => (2, "Completed"),
+        TaskStatus::Cancelled => (3, "Cancelled"),
+    }
+}
+
+/// 按状态分组，并按既定顺序排列列；列内按截止时间升序
+fn group_by_status(tasks: &[TaskResponse]) -> Vec<(&'static str, Vec<&TaskResponse>)> {
+    let mut groups: BTreeMap<(u8, &'static str), Vec<&TaskResponse>> = BTreeMap::new();
+    for task in tasks {
+        groups
+            .entry(status_column(&task.status))
+            .or_default()
+            .push(task);
+    }
+
+    groups
+        .into_iter()
+        .map(|((_, label), mut tasks)| {
+            tasks.sort_by_key(|task| (task.deadline.is_none(), task.deadline));
+            (label, tasks)
+        })
+        .collect()
+}
+
+fn to_map<'a>(
+    columns: &[(&'static str, Vec<&'a TaskResponse>)],
+) -> BTreeMap<String, Vec<&'a TaskResponse>> {
+    columns
+        .iter()
+        .map(|(label, tasks)| (label.to_lowercase(), tasks.clone()))
+        .collect()
+}
+
+/// 根据终端宽度计算每行列数和列宽
+fn layout(total_width: usize, column_count: usize) -> (usize, usize) {
+    let gap = COLUMN_GAP.len();
+    let fit = ((total_width + gap) / (MIN_COLUMN_WIDTH + gap)).max(1);
+    let per_row = column_count.clamp(1, fit);
+    let width = ((total_width + gap) / per_row).saturating_sub(gap);
+    (per_row, width.max(MIN_COLUMN_WIDTH.min(total_width)))
+}
+
+fn print_board(
+    columns: &[(&'static str, Vec<&TaskResponse>)],
+    total_width: usize,
+    now: DateTime<Utc>,
+    soon: chrono::Duration,
+) {
+    let (per_row, width) = layout(total_width, columns.len());
+
+    for row in columns.chunks(per_row) {
+        let rendered: Vec<Vec<String>> = row
+            .iter()
+            .map(|(label, tasks)| render_column(label, tasks, width, now, soon))
+            .collect();
+        let height = rendered.iter().map(Vec::len).max().unwrap_or(0);
+        let blank = " ".repeat(width);
+
+        for line in 0..height {
+            let cells: Vec<&str> = rendered
+                .iter()
+                .map(|column| column.get(line).map(String::as_str).unwrap_or(&blank))
+                .collect();
+            println!("{}", cells.join(COLUMN_GAP).trim_end());
+        }
+        println!();
+    }
+}
+
+fn render_column(
+    label: &str,
+    tasks: &[&TaskResponse],
+    width: usize,
+    now: DateTime<Utc>,
+    soon: chrono::Duration,
+) -> Vec<String> {
+    let cell = |text: String| pad_str(&text, width, Alignment::Left, Some("…")).into_owned();
+
+    let header = format!("{} ({})", label, tasks.len());
+    let mut lines = vec![
+        cell(header.bold().to_string()),
+        cell("─".repeat(width).dimmed().to_string()),
+    ];
+
+    for task in tasks {
+        let urgency = task
+            .deadline
+            .map(|deadline| deadline_urgency(deadline, now, soon))
+            .unwrap_or(DeadlineUrgency::Normal);
+
+        let title = truncate_str(&task.title, width.saturating_sub(2), "…").into_owned();
+        let title = match urgency {
+            DeadlineUrgency::Overdue => format!("{} {}", "●".red(), title.red().bold()),
+            DeadlineUrgency::DueSoon => format!("{} {}", "●".yellow(), title.yellow().bold()),
+            DeadlineUrgency::Normal => format!("{} {}", "●".dimmed(), title.bold()),
+        };
+
+        let countdown = match task.deadline {
+            Some(deadline) => {
+                let text = format_countdown(deadline, now);
+                match urgency {
+                    DeadlineUrgency::Overdue => text.red().to_string(),
+                    DeadlineUrgency::DueSoon => text.yellow().to_string(),
+                    DeadlineUrgency::Normal => text,
+                }
+            }
+            None => "no deadline".dimmed().to_string(),
+        };
+
+        lines.push(cell(title));
+        lines.push(cell(format!("  {}", format_budget(task))));
+        lines.push(cell(format!("  {}", countdown)));
+        lines.push(cell(String::new()));
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_columns_follow_the_task_lifecycle() {
+        let ranks: Vec<u8> = [
+            TaskStatus::Open,
+            TaskStatus::InProgress,
+            TaskStatus::Completed,
+            TaskStatus::Cancelled,
+        ]
+        .iter()
+        .map(|status| status_column(status).0)
+        .collect();
+        assert_eq!(ranks, vec![0, 1, 2, 3]);
+        assert_eq!(status_column(&TaskStatus::InProgress).1, "In Progress");
+    }
+
+    #[test]
+    fn test_layout_fits_all_columns_on_wide_terminals() {
+        let (per_row, width) = layout(160, 4);
+        assert_eq!(per_row, 4);
+        assert!(width * 4 + COLUMN_GAP.len() * 3 <= 160);
+    }
+
+    #[test]
+    fn test_layout_wraps_columns_on_narrow_terminals() {
+        let (per_row, width) = layout(60, 4);
+        assert_eq!(per_row, 2);
+        assert!(width >= MIN_COLUMN_WIDTH);
+        assert!(width * 2 + COLUMN_GAP.len() <= 60);
+    }
+}
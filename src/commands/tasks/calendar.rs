@@ -0,0 +1,243 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use clap::Subcommand;
+use colored::Colorize;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::api::ApiClient;
+use crate::models::TaskResponse;
+use crate::utils::output::{print_error, print_success};
+use crate::utils::state;
+use crate::utils::time::{parse_duration, parse_interval, sleep_or_interrupt};
+
+#[derive(Subcommand)]
+pub enum TaskCalendarCommands {
+    /// 将当前 agent 任务的截止时间导出为 iCalendar (.ics) 文件
+    Export {
+        /// 输出文件路径，`-` 表示输出到标准输出
+        #[arg(short, long, default_value = "agentlink-tasks.ics")]
+        out: String,
+
+        /// 持续监听任务变化并重新生成文件
+        #[arg(short, long)]
+        watch: bool,
+
+        /// 监听模式下的刷新间隔（例如 30s、5m、1h）
+        #[arg(long, default_value = "5m", value_parser = parse_interval)]
+        interval: Duration,
+
+        /// 在截止前多久触发日历提醒（例如 24h）；不指定则不添加提醒
+        #[arg(long, value_parser = parse_duration)]
+        alarm: Option<Duration>,
+    },
+}
+
+pub async fn execute(command: TaskCalendarCommands, client: &ApiClient) -> Result<()> {
+    match command {
+        TaskCalendarCommands::Export {
+            out,
+            watch,
+            interval,
+            alarm,
+        } => {
+            if watch && out == "-" {
+                anyhow::bail!("`--watch` requires an output file; stdout is not supported.");
+            }
+
+            let alarm = alarm.map(|value| ChronoDuration::seconds(value.as_secs() as i64));
+
+            if watch {
+                println!(
+                    "{}",
+                    format!(
+                        "Watching tasks, regenerating {} every {}s. Press Ctrl+C to exit.",
+                        out,
+                        interval.as_secs()
+                    )
+                    .cyan()
+                );
+            }
+
+            let mut last_rendered: Option<String> = None;
+            loop {
+                match client.get_my_tasks().await {
+                    Ok(response) => {
+                        let calendar = render_calendar(&response.tasks, alarm);
+                        let count = response
+                            .tasks
+                            .iter()
+                            .filter(|task| task.deadline.is_some())
+                            .count();
+
+                        if out == "-" {
+                            print!("{}", calendar);
+                        } else if last_rendered.as_deref() != Some(calendar.as_str()) {
+                            state::write_atomically(Path::new(&out), &calendar)?;
+                            print_success(&format!(
+                                "Exported {} task deadline(s) to {}",
+                                count, out
+                            ));
+                            last_rendered = Some(calendar);
+                        }
+                    }
+                    Err(error) => {
+                        print_error(&format!("Failed to get my tasks: {}", error));
+                    }
+                }
+
+                if !watch || !sleep_or_interrupt(interval).await {
+                    break;
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// 生成 iCalendar 内容，仅包含设置了截止时间的任务
+pub fn render_calendar(tasks: &[TaskResponse], alarm: Option<ChronoDuration>) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!(
+            "PRODID:-//AgentLink//agentlink-cli {}//EN",
+            env!("CARGO_PKG_VERSION")
+        ),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        "X-WR-CALNAME:AgentLink Tasks".to_string(),
+    ];
+
+    for task in tasks {
+        let Some(deadline) = task.deadline else {
+            continue;
+        };
+
+        let status = format!("{:?}", task.status).to_lowercase();
+        let link = format!("https://agentlink.chat/tasks/{}", task.id);
+        let description = format!(
+            "Budget: {}\nStatus: {}\nApplications: {}\n\n{}",
+            super::format_budget(task),
+            status,
+            task.application_count,
+            link
+        );
+
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:task-{}@agentlink.chat", task.id));
+        lines.push(format!("DTSTAMP:{}", format_ical_time(&task.created_at)));
+        lines.push(format!(
+            "DTSTART:{}",
+            format_ical_time(&(deadline - ChronoDuration::minutes(30)))
+        ));
+        lines.push(format!("DTEND:{}", format_ical_time(&deadline)));
+        lines.push(format!("SUMMARY:{}", escape_text(&task.title)));
+        lines.push(format!("DESCRIPTION:{}", escape_text(&description)));
+        lines.push(format!("URL:{}", link));
+        lines.push(format!("CATEGORIES:{}", escape_text(&status)));
+
+        if let Some(alarm) = alarm {
+            lines.push("BEGIN:VALARM".to_string());
+            lines.push("ACTION:DISPLAY".to_string());
+            lines.push(format!(
+                "DESCRIPTION:{}",
+                escape_text(&format!("Task due: {}", task.title))
+            ));
+            lines.push(format!("TRIGGER;RELATED=END:-PT{}M", alarm.num_minutes()));
+            lines.push("END:VALARM".to_string());
+        }
+
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<_>>()
+        .join("\r\n")
+        + "\r\n"
+}
+
+fn format_ical_time(time: &DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// 按 RFC 5545 转义 TEXT 值
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// 按 RFC 5545 将超过 75 字节的内容行折行，保证不切断 UTF-8 字符
+fn fold_line(line: &str) -> String {
+    const MAX_OCTETS: usize = 75;
+
+    let mut folded = String::with_capacity(line.len() + line.len() / MAX_OCTETS * 3);
+    let mut current = 0;
+    let mut limit = MAX_OCTETS;
+
+    for ch in line.chars() {
+        if current + ch.len_utf8() > limit {
+            folded.push_str("\r\n ");
+            current = 0;
+            // 续行以一个空格开头，因此续行可用长度少 1
+            limit = MAX_OCTETS - 1;
+        }
+
+        folded.push(ch);
+        current += ch.len_utf8();
+    }
+
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_text_escapes_special_characters() {
+        assert_eq!(
+            escape_text("Budget: 10,20; path\\x\nnext"),
+            "Budget: 10\\,20\\; path\\\\x\\nnext"
+        );
+    }
+
+    #[test]
+    fn test_fold_line_keeps_lines_within_75_octets() {
+        let line = format!("SUMMARY:{}", "任务".repeat(40));
+        let folded = fold_line(&line);
+
+        for (index, part) in folded.split("\r\n").enumerate() {
+            assert!(part.len() <= 75, "line {} is {} octets", index, part.len());
+            if index > 0 {
+                assert!(part.starts_with(' '));
+            }
+        }
+
+        let unfolded = folded.replace("\r\n ", "");
+        assert_eq!(unfolded, line);
+    }
+
+    #[test]
+    fn test_render_calendar_without_tasks_is_valid_envelope() {
+        let calendar = render_calendar(&[], None);
+        assert!(calendar.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(calendar.ends_with("END:VCALENDAR\r\n"));
+        assert!(!calendar.contains("BEGIN:VEVENT"));
+    }
+}
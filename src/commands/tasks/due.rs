@@ -0,0 +1,491 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+use super::{deadline_urgency, format_countdown, is_closed, DeadlineUrgency};
+use crate::api::ApiClient;
+use crate::config::Config;
+use crate::models::{MessageType, SendMessageRequest, TaskResponse};
+use crate::utils::output::{print_error, print_success, print_table, print_warning};
+use crate::utils::time::{format_duration_short, sleep_or_interrupt};
+use crate::utils::{hook, state, template};
+
+/// 已触发提醒的状态文件
+const REMINDER_STATE_FILE: &str = "task_reminders.json";
+
+/// 提醒记录保留天数
+const REMINDER_RETENTION_DAYS: i64 = 30;
+
+/// 默认提醒消息模板
+pub const DEFAULT_REMINDER_TEMPLATE: &str =
+    "⏰ Reminder: \"{title}\" is {countdown} (deadline {deadline}). {link}";
+
+/// 列出截止时间落在窗口内（以及已逾期）的任务
+pub async fn list_due(
+    client: &ApiClient,
+    within: Duration,
+    include_overdue: bool,
+    format: crate::OutputFormat,
+) -> Result<()> {
+    let response = match client.get_my_tasks().await {
+        Ok(response) => response,
+        Err(error) => {
+            print_error(&format!("Failed to get my tasks: {}", error));
+            return Ok(());
+        }
+    };
+
+    let now = Utc::now();
+    let within = ChronoDuration::from_std(within)?;
+    let mut tasks: Vec<&TaskResponse> = response
+        .tasks
+        .iter()
+        .filter(|task| !is_closed(task))
+        .filter(|task| match task.deadline {
+            Some(deadline) if deadline <= now => include_overdue,
+            Some(deadline) => deadline - now <= within,
+            None => false,
+        })
+        .collect();
+    tasks.sort_by_key(|task| task.deadline);
+
+    if tasks.is_empty() {
+        println!(
+            "{}",
+            format!("No tasks due within {}.", format_duration_short(within)).green()
+        );
+        return Ok(());
+    }
+
+    match format {
+        crate::OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&tasks)?);
+        }
+        crate::OutputFormat::Yaml => {
+            println!("{}", serde_yaml::to_string(&tasks)?);
+        }
+        _ => {
+            println!(
+                "\n{} (next {}):\n",
+                "Upcoming Deadlines".bold().underline(),
+                format_duration_short(within)
+            );
+
+            let rows: Vec<Vec<String>> = tasks
+                .iter()
+                .filter_map(|task| {
+                    let deadline = task.deadline?;
+                    let countdown = format_countdown(deadline, now);
+                    let countdown = match deadline_urgency(deadline, now, within) {
+                        DeadlineUrgency::Overdue => countdown.red().bold().to_string(),
+                        _ => countdown.yellow().to_string(),
+                    };
+
+                    Some(vec![
+                        task.id.to_string(),
+                        task.title.clone(),
+                        format!("{:?}", task.status).to_lowercase(),
+                        deadline.format("%Y-%m-%d %H:%M").to_string(),
+                        countdown,
+                    ])
+                })
+                .collect();
+
+            print_table(vec!["ID", "Title", "Status", "Deadline", "Due"], rows);
+
+            let overdue = tasks
+                .iter()
+                .filter(|task| task.deadline.is_some_and(|deadline| deadline <= now))
+                .count();
+            if overdue > 0 {
+                println!("\n{}", format!("{} task(s) overdue!", overdue).red().bold());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// 提醒投递方式
+pub struct ReminderTargets {
+    pub exec: Option<String>,
+    pub conversation_id: Option<String>,
+    pub template: String,
+}
+
+/// 提醒参数
+pub struct RemindOptions {
+    pub leads: Vec<Duration>,
+    pub overdue: bool,
+    pub interval: Duration,
+    pub once: bool,
+    pub dry_run: bool,
+}
+
+/// 已触发的提醒，键包含截止时间，截止时间变化后会重新提醒
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ReminderState {
+    fired: BTreeSet<String>,
+    /// 尚未全部完成的提醒中已成功的动作，键为 `<提醒键>#exec` 或 `<提醒键>#message`
+    #[serde(default)]
+    completed_steps: BTreeSet<String>,
+}
+
+impl ReminderState {
+    /// 丢弃截止时间早于 `cutoff` 的记录，避免状态文件无限增长
+    fn prune(&mut self, cutoff: DateTime<Utc>) {
+        let keep = |key: &String| {
+            key.split(':')
+                .nth(1)
+                .and_then(|timestamp| timestamp.parse::<i64>().ok())
+                .map_or(true, |timestamp| timestamp >= cutoff.timestamp())
+        };
+        self.fired.retain(keep);
+        self.completed_steps.retain(keep);
+    }
+
+    /// 提醒全部投递完成：记录已触发的键并清理其分步记录
+    fn finish(&mut self, keys: impl IntoIterator<Item = String>) {
+        for key in keys {
+            let prefix = format!("{}#", key);
+            self.completed_steps
+                .retain(|step| !step.starts_with(&prefix));
+            self.fired.insert(key);
+        }
+    }
+}
+
+/// 提醒状态及其保存位置；dry-run 时只在内存中记录
+struct ReminderStore<'a> {
+    config: &'a Config,
+    state: ReminderState,
+    dry_run: bool,
+}
+
+impl ReminderStore<'_> {
+    fn save(&self) -> Result<()> {
+        if self.dry_run {
+            return Ok(());
+        }
+        state::save(self.config, REMINDER_STATE_FILE, &self.state)
+    }
+
+    fn step_done(&self, step: &str) -> bool {
+        self.state.completed_steps.contains(step)
+    }
+
+    /// 单个动作成功后立即保存，后续动作失败重试时不会重复执行
+    fn complete_step(&mut self, step: String) -> Result<()> {
+        self.state.completed_steps.insert(step);
+        self.save()
+    }
+}
+
+/// 需要触发的一次提醒
+#[derive(Debug, Clone, PartialEq)]
+struct DueReminder {
+    key: String,
+    lead: Option<ChronoDuration>,
+}
+
+/// 长期运行：在设定的提前量到达时触发钩子或发送消息
+pub async fn remind(
+    client: &ApiClient,
+    config: &Config,
+    targets: ReminderTargets,
+    options: RemindOptions,
+) -> Result<()> {
+    let mut leads = options
+        .leads
+        .iter()
+        .map(|lead| ChronoDuration::from_std(*lead))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    leads.sort();
+    leads.dedup();
+
+    if !options.once {
+        println!(
+            "{}",
+            format!(
+                "Watching deadlines (lead times: {}), checking every {}s. Press Ctrl+C to exit.",
+                leads
+                    .iter()
+                    .map(|lead| format_duration_short(*lead))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                options.interval.as_secs()
+            )
+            .cyan()
+        );
+    }
+
+    let mut store = ReminderStore {
+        config,
+        state: state::load(config, REMINDER_STATE_FILE)?,
+        dry_run: options.dry_run,
+    };
+    store
+        .state
+        .prune(Utc::now() - ChronoDuration::days(REMINDER_RETENTION_DAYS));
+
+    loop {
+        match client.get_my_tasks().await {
+            Ok(response) => {
+                let now = Utc::now();
+                for task in response.tasks.iter().filter(|task| !is_closed(task)) {
+                    let Some(deadline) = task.deadline else {
+                        continue;
+                    };
+
+                    let Some(reminder) = pending_reminder(
+                        &task.id.to_string(),
+                        deadline,
+                        now,
+                        &leads,
+                        options.overdue,
+                        &store.state.fired,
+                    ) else {
+                        continue;
+                    };
+
+                    match deliver(client, &targets, task, deadline, now, &reminder, &mut store)
+                        .await
+                    {
+                        Ok(()) => {
+                            // 同时标记更长的提前量，避免首次启动时连续补发
+                            let mut keys =
+                                superseded_keys(&task.id.to_string(), deadline, &leads, &reminder);
+                            keys.push(reminder.key.clone());
+                            store.state.finish(keys);
+                            store.save()?;
+                        }
+                        Err(error) => {
+                            print_error(&format!(
+                                "Failed to deliver reminder for task {}: {}",
+                                task.id, error
+                            ));
+                        }
+                    }
+                }
+            }
+            Err(error) => {
+                print_error(&format!("Failed to get my tasks: {}", error));
+            }
+        }
+
+        if options.once || !sleep_or_interrupt(options.interval).await {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn reminder_key(task_id: &str, deadline: DateTime<Utc>, lead: Option<ChronoDuration>) -> String {
+    let lead = lead
+        .map(|lead| lead.num_seconds().to_string())
+        .unwrap_or_else(|| "overdue".to_string());
+    format!("{}:{}:{}", task_id, deadline.timestamp(), lead)
+}
+
+/// 计算当前应触发的提醒：选择已进入窗口且尚未触发的最短提前量
+fn pending_reminder(
+    task_id: &str,
+    deadline: DateTime<Utc>,
+    now: DateTime<Utc>,
+    leads: &[ChronoDuration],
+    overdue: bool,
+    fired: &BTreeSet<String>,
+) -> Option<DueReminder> {
+    if deadline <= now {
+        let key = reminder_key(task_id, deadline, None);
+        return (overdue && !fired.contains(&key)).then_some(DueReminder { key, lead: None });
+    }
+
+    let remaining = deadline - now;
+    leads
+        .iter()
+        .filter(|lead| remaining <= **lead)
+        .min()
+        .map(|lead| DueReminder {
+            key: reminder_key(task_id, deadline, Some(*lead)),
+            lead: Some(*lead),
+        })
+        .filter(|reminder| !fired.contains(&reminder.key))
+}
+
+fn superseded_keys(
+    task_id: &str,
+    deadline: DateTime<Utc>,
+    leads: &[ChronoDuration],
+    reminder: &DueReminder,
+) -> Vec<String> {
+    leads
+        .iter()
+        .filter(|lead| reminder.lead.map_or(true, |current| **lead > current))
+        .map(|lead| reminder_key(task_id, deadline, Some(*lead)))
+        .collect()
+}
+
+async fn deliver(
+    client: &ApiClient,
+    targets: &ReminderTargets,
+    task: &TaskResponse,
+    deadline: DateTime<Utc>,
+    now: DateTime<Utc>,
+    reminder: &DueReminder,
+    store: &mut ReminderStore<'_>,
+) -> Result<()> {
+    let countdown = format_countdown(deadline, now);
+    let lead = reminder
+        .lead
+        .map(format_duration_short)
+        .unwrap_or_else(|| "overdue".to_string());
+    let link = format!("https://agentlink.chat/tasks/{}", task.id);
+    let values = [
+        ("id", task.id.to_string()),
+        ("title", task.title.clone()),
+        ("status", format!("{:?}", task.status).to_lowercase()),
+        (
+            "deadline",
+            deadline.format("%Y-%m-%d %H:%M UTC").to_string(),
+        ),
+        ("countdown", countdown.clone()),
+        ("lead", lead.clone()),
+        ("link", link.clone()),
+    ];
+    let message = template::render(&targets.template, &values);
+
+    if store.dry_run {
+        print_warning(&format!("[dry-run] {}", message));
+        return Ok(());
+    }
+
+    let exec_step = format!("{}#exec", reminder.key);
+    if let Some(command) = targets
+        .exec
+        .as_ref()
+        .filter(|_| !store.step_done(&exec_step))
+    {
+        let env: Vec<(&str, String)> = vec![
+            ("AGENTLINK_TASK_ID", task.id.to_string()),
+            ("AGENTLINK_TASK_TITLE", task.title.clone()),
+            ("AGENTLINK_TASK_DEADLINE", deadline.to_rfc3339()),
+            ("AGENTLINK_TASK_COUNTDOWN", countdown),
+            ("AGENTLINK_TASK_URL", link),
+            ("AGENTLINK_REMINDER_LEAD", lead),
+            ("AGENTLINK_REMINDER_MESSAGE", message.clone()),
+        ];
+        hook::run(command, &env, Some(serde_json::to_string(task)?)).await?;
+        store.complete_step(exec_step)?;
+    }
+
+    let message_step = format!("{}#message", reminder.key);
+    if let Some(conversation_id) = targets
+        .conversation_id
+        .as_ref()
+        .filter(|_| !store.step_done(&message_step))
+    {
+        client
+            .send_message(
+                conversation_id,
+                SendMessageRequest {
+                    content: message.clone(),
+                    kind: Some(MessageType::Text),
+                    metadata: None,
+                    reply_to: None,
+                },
+            )
+            .await?;
+        store.complete_step(message_step)?;
+    }
+
+    print_success(&message);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn leads() -> Vec<ChronoDuration> {
+        vec![ChronoDuration::hours(1), ChronoDuration::hours(24)]
+    }
+
+    #[test]
+    fn test_pending_reminder_waits_until_lead_window() {
+        let now = Utc.with_ymd_and_hms(2026, 11, 1, 9, 0, 0).unwrap();
+        let deadline = now + ChronoDuration::hours(30);
+
+        assert_eq!(
+            pending_reminder("t1", deadline, now, &leads(), false, &BTreeSet::new()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_pending_reminder_picks_shortest_open_lead() {
+        let now = Utc.with_ymd_and_hms(2026, 11, 1, 9, 0, 0).unwrap();
+        let deadline = now + ChronoDuration::minutes(30);
+
+        let reminder =
+            pending_reminder("t1", deadline, now, &leads(), false, &BTreeSet::new()).unwrap();
+        assert_eq!(reminder.lead, Some(ChronoDuration::hours(1)));
+
+        let superseded = superseded_keys("t1", deadline, &leads(), &reminder);
+        assert_eq!(
+            superseded,
+            vec![reminder_key(
+                "t1",
+                deadline,
+                Some(ChronoDuration::hours(24))
+            )]
+        );
+    }
+
+    #[test]
+    fn test_pending_reminder_skips_fired_and_handles_overdue() {
+        let now = Utc.with_ymd_and_hms(2026, 11, 1, 9, 0, 0).unwrap();
+        let deadline = now + ChronoDuration::hours(12);
+
+        let mut fired = BTreeSet::new();
+        fired.insert(reminder_key(
+            "t1",
+            deadline,
+            Some(ChronoDuration::hours(24)),
+        ));
+        assert_eq!(
+            pending_reminder("t1", deadline, now, &leads(), false, &fired),
+            None
+        );
+
+        let overdue_deadline = now - ChronoDuration::minutes(5);
+        assert_eq!(
+            pending_reminder("t1", overdue_deadline, now, &leads(), false, &fired),
+            None
+        );
+        assert!(pending_reminder("t1", overdue_deadline, now, &leads(), true, &fired).is_some());
+    }
+
+    #[test]
+    fn test_finish_clears_completed_steps_of_the_reminder() {
+        let deadline = Utc.with_ymd_and_hms(2026, 11, 1, 9, 0, 0).unwrap();
+        let key = reminder_key("t1", deadline, Some(ChronoDuration::hours(1)));
+        let other = reminder_key("t2", deadline, Some(ChronoDuration::hours(1)));
+
+        let mut state = ReminderState::default();
+        state.completed_steps.insert(format!("{}#exec", key));
+        state.completed_steps.insert(format!("{}#exec", other));
+        state.finish(vec![key.clone()]);
+
+        assert!(state.fired.contains(&key));
+        assert_eq!(
+            state.completed_steps.into_iter().collect::<Vec<_>>(),
+            vec![format!("{}#exec", other)]
+        );
+    }
+}
@@ -1,12 +1,19 @@
 use anyhow::Result;
 use clap::Subcommand;
 use colored::Colorize;
+use std::path::PathBuf;
+use std::time::Duration;
 
 use crate::api::ApiClient;
 use crate::config::Config;
-use crate::models::{CreateApplicationRequest, TaskResponse};
+use crate::models::{CreateApplicationRequest, TaskResponse, TaskStatus};
 use crate::utils::output::{print_error, print_success, print_table};
+use crate::utils::time::{format_duration_short, parse_duration, parse_interval};
 
+pub mod batch;
+pub mod board;
+pub mod calendar;
+pub mod due;
 pub mod publish;
 
 #[derive(Subcommand)]
@@ -40,9 +47,107 @@ pub enum TaskCommands {
         days: Option<i32>,
     },
 
+    /// 批量申请任务（CSV 或 NDJSON 输入）
+    ApplyBatch {
+        /// 输入文件路径，`-` 表示从标准输入读取
+        #[arg(short, long)]
+        input: String,
+
+        /// 输入格式，默认根据文件扩展名推断
+        #[arg(long = "input-format", value_enum)]
+        input_format: Option<batch::BatchInputFormat>,
+
+        /// 输入中未指定时使用的默认求职信
+        #[arg(short, long)]
+        cover_letter: Option<String>,
+
+        /// 最大并发请求数
+        #[arg(long, default_value = "4")]
+        concurrency: usize,
+
+        /// 仅处理输入中的前 N 个任务
+        #[arg(short = 'n', long)]
+        limit: Option<usize>,
+
+        /// 将结果写入 JSON 文件
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+
+        /// 仅预览将要提交的申请
+        #[arg(long)]
+        dry_run: bool,
+    },
+
     /// 查看当前 agent 相关任务
     MyTasks,
 
+    /// 按状态分列的任务看板
+    Board {
+        /// 持续刷新看板
+        #[arg(short, long)]
+        watch: bool,
+
+        /// 刷新间隔（例如 30s、1m）
+        #[arg(long, default_value = "1m", value_parser = parse_interval)]
+        interval: Duration,
+
+        /// 距截止时间小于该值的任务会高亮（例如 24h、48h）
+        #[arg(long, default_value = "48h", value_parser = parse_duration)]
+        soon: Duration,
+    },
+
+    /// 列出即将到期和已逾期的任务
+    Due {
+        /// 截止时间窗口（例如 24h、3d）
+        #[arg(short, long, default_value = "72h", value_parser = parse_duration)]
+        within: Duration,
+
+        /// 不显示已逾期的任务
+        #[arg(long)]
+        no_overdue: bool,
+    },
+
+    /// 截止提醒：在提前量到达时执行钩子或向会话发送消息（长期运行）
+    Remind {
+        /// 提前提醒时间，可重复指定（例如 --lead 24h --lead 1h）
+        #[arg(long = "lead", default_values = ["24h", "1h"], value_parser = parse_duration)]
+        leads: Vec<Duration>,
+
+        /// 触发时执行的 shell 命令；任务信息通过 AGENTLINK_TASK_* 环境变量和标准输入 JSON 传入
+        #[arg(long, required_unless_present = "conversation")]
+        exec: Option<String>,
+
+        /// 触发时发送提醒消息的会话 ID
+        #[arg(long)]
+        conversation: Option<String>,
+
+        /// 提醒消息模板，支持 {id} {title} {status} {deadline} {countdown} {lead} {link}
+        #[arg(long, default_value = due::DEFAULT_REMINDER_TEMPLATE)]
+        message: String,
+
+        /// 任务逾期时额外提醒一次
+        #[arg(long)]
+        overdue: bool,
+
+        /// 检查间隔
+        #[arg(long, default_value = "5m", value_parser = parse_interval)]
+        interval: Duration,
+
+        /// 只检查一次后退出（适合 cron）
+        #[arg(long)]
+        once: bool,
+
+        /// 仅打印将要发送的提醒
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// 任务日历导出
+    Calendar {
+        #[command(subcommand)]
+        command: calendar::TaskCalendarCommands,
+    },
+
     /// 发布新任务（交互式向导）
     Publish {
         /// 从草稿恢复继续
@@ -154,12 +259,7 @@ pub async fn execute(
         } => {
             ensure_authenticated(config)?;
 
-            let body = CreateApplicationRequest {
-                task_id: None,
-                cover_letter,
-                proposed_budget: budget.and_then(rust_decimal::Decimal::from_f64_retain),
-                estimated_days: days,
-            };
+            let body = application_request(cover_letter, budget, days);
 
             match client.apply_to_task(&id, body).await {
                 Ok(application) => {
@@ -178,6 +278,34 @@ pub async fn execute(
                 }
             }
         }
+        TaskCommands::ApplyBatch {
+            input,
+            input_format,
+            cover_letter,
+            concurrency,
+            limit,
+            output,
+            dry_run,
+        } => {
+            if !dry_run {
+                ensure_authenticated(config)?;
+            }
+
+            batch::run(
+                &client,
+                batch::BatchApplyOptions {
+                    input,
+                    input_format,
+                    cover_letter,
+                    concurrency,
+                    limit,
+                    output,
+                    dry_run,
+                },
+                format,
+            )
+            .await
+        }
         TaskCommands::MyTasks => {
             ensure_authenticated(config)?;
 
@@ -230,6 +358,62 @@ pub async fn execute(
                 }
             }
         }
+        TaskCommands::Board {
+            watch,
+            interval,
+            soon,
+        } => {
+            ensure_authenticated(config)?;
+
+            board::run(
+                &client,
+                board::BoardOptions {
+                    watch,
+                    interval,
+                    soon: chrono::Duration::from_std(soon)?,
+                },
+                format,
+            )
+            .await
+        }
+        TaskCommands::Due { within, no_overdue } => {
+            ensure_authenticated(config)?;
+            due::list_due(&client, within, !no_overdue, format).await
+        }
+        TaskCommands::Remind {
+            leads,
+            exec,
+            conversation,
+            message,
+            overdue,
+            interval,
+            once,
+            dry_run,
+        } => {
+            ensure_authenticated(config)?;
+
+            due::remind(
+                &client,
+                config,
+                due::ReminderTargets {
+                    exec,
+                    conversation_id: conversation,
+                    template: message,
+                },
+                due::RemindOptions {
+                    leads,
+                    overdue,
+                    interval,
+                    once,
+                    dry_run,
+                },
+            )
+            .await
+        }
+        TaskCommands::Calendar { command } => {
+            ensure_authenticated(config)?;
+            calendar::execute(command, &client).await
+        }
         TaskCommands::Publish { .. } => {
             ensure_authenticated(config)?;
             
@@ -249,6 +433,19 @@ fn ensure_authenticated(config: &Config) -> Result<()> {
     }
 }
 
+fn application_request(
+    cover_letter: Option<String>,
+    budget: Option<f64>,
+    days: Option<i32>,
+) -> CreateApplicationRequest {
+    CreateApplicationRequest {
+        task_id: None,
+        cover_letter,
+        proposed_budget: budget.and_then(rust_decimal::Decimal::from_f64_retain),
+        estimated_days: days,
+    }
+}
+
 fn format_budget(task: &TaskResponse) -> String {
     match (&task.budget_min, &task.budget_max) {
         (Some(min), Some(max)) => format!("{}-{} {}", min, max, task.currency),
@@ -262,6 +459,46 @@ fn format_date(date: &chrono::DateTime<chrono::Utc>) -> String {
     date.format("%Y-%m-%d").to_string()
 }
 
+/// 已结束的任务不参与截止提醒
+fn is_closed(task: &TaskResponse) -> bool {
+    matches!(task.status, TaskStatus::Completed | TaskStatus::Cancelled)
+}
+
+/// 截止时间紧迫程度
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DeadlineUrgency {
+    Overdue,
+    DueSoon,
+    Normal,
+}
+
+fn deadline_urgency(
+    deadline: chrono::DateTime<chrono::Utc>,
+    now: chrono::DateTime<chrono::Utc>,
+    soon: chrono::Duration,
+) -> DeadlineUrgency {
+    if deadline <= now {
+        DeadlineUrgency::Overdue
+    } else if deadline - now <= soon {
+        DeadlineUrgency::DueSoon
+    } else {
+        DeadlineUrgency::Normal
+    }
+}
+
+/// 格式化截止倒计时，例如 `due in 2d 4h`、`overdue 3h 10m`
+fn format_countdown(
+    deadline: chrono::DateTime<chrono::Utc>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> String {
+    let remaining = deadline - now;
+    if remaining <= chrono::Duration::zero() {
+        format!("overdue {}", format_duration_short(remaining))
+    } else {
+        format!("due in {}", format_duration_short(remaining))
+    }
+}
+
 fn print_task_details(task: &TaskResponse) {
     println!("\n{}", "Task Details".bold().underline());
     println!();
@@ -309,3 +546,42 @@ fn print_task_details(task: &TaskResponse) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+
+    #[test]
+    fn test_deadline_urgency_thresholds() {
+        let now = Utc.with_ymd_and_hms(2026, 11, 1, 9, 0, 0).unwrap();
+        let soon = Duration::hours(48);
+
+        assert_eq!(
+            deadline_urgency(now - Duration::minutes(1), now, soon),
+            DeadlineUrgency::Overdue
+        );
+        assert_eq!(
+            deadline_urgency(now + Duration::hours(47), now, soon),
+            DeadlineUrgency::DueSoon
+        );
+        assert_eq!(
+            deadline_urgency(now + Duration::hours(72), now, soon),
+            DeadlineUrgency::Normal
+        );
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        let now = Utc.with_ymd_and_hms(2026, 11, 1, 9, 0, 0).unwrap();
+
+        assert_eq!(
+            format_countdown(now + Duration::hours(28), now),
+            "due in 1d 4h"
+        );
+        assert_eq!(
+            format_countdown(now - Duration::minutes(190), now),
+            "overdue 3h 10m"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use anyhow::{Context, Result};
+use anyhow::Result;
 use chrono::{DateTime, NaiveDate, Utc};
 use colored::Colorize;
 use dialoguer::{Confirm, Editor, Input, MultiSelect, Select};
@@ -7,6 +7,7 @@ use std::path::PathBuf;
 use uuid::Uuid;
 
 use crate::api::ApiClient;
+use crate::commands::skills::SkillCatalog;
 use crate::config::Config;
 use crate::utils::output::{print_error, print_success};
 
@@ -63,15 +64,14 @@ impl TaskType {
 }
 
 /// 草稿存储路径
-fn draft_path() -> Result<PathBuf> {
-    let config_dir = dirs::config_dir().context("Failed to get config directory")?;
-    Ok(config_dir.join("agentlink").join("task_draft.toml"))
+fn draft_path(config: &Config) -> Result<PathBuf> {
+    config.state_file_path("task_draft.toml")
 }
 
 impl TaskDraft {
     /// 保存草稿到文件
-    pub fn save(&self) -> Result<()> {
-        let path = draft_path()?;
+    pub fn save(&self, config: &Config) -> Result<()> {
+        let path = draft_path(config)?;
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
@@ -81,8 +81,8 @@ impl TaskDraft {
     }
 
     /// 从文件加载草稿
-    pub fn load() -> Result<Option<Self>> {
-        let path = draft_path()?;
+    pub fn load(config: &Config) -> Result<Option<Self>> {
+        let path = draft_path(config)?;
         if !path.exists() {
             return Ok(None);
         }
@@ -92,8 +92,8 @@ impl TaskDraft {
     }
 
     /// 清除草稿
-    pub fn clear() -> Result<()> {
-        let path = draft_path()?;
+    pub fn clear(config: &Config) -> Result<()> {
+        let path = draft_path(config)?;
         if path.exists() {
             std::fs::remove_file(&path)?;
         }
@@ -101,8 +101,8 @@ impl TaskDraft {
     }
 
     /// 检查是否有草稿
-    pub fn exists() -> bool {
-        draft_path().map(|p| p.exists()).unwrap_or(false)
+    pub fn exists(config: &Config) -> bool {
+        draft_path(config).map(|p| p.exists()).unwrap_or(false)
     }
 }
 
@@ -125,58 +125,58 @@ impl TaskPublishWizard {
         println!("{}", "This wizard will guide you through creating a new task.\n".dimmed());
 
         // 检查是否有草稿
-        if TaskDraft::exists() {
+        if TaskDraft::exists(&self.config) {
             let should_resume = Confirm::new()
                 .with_prompt("A draft was found. Do you want to resume from where you left off?")
                 .default(true)
                 .interact()?;
 
             if should_resume {
-                if let Some(draft) = TaskDraft::load()? {
+                if let Some(draft) = TaskDraft::load(&self.config)? {
                     self.draft = draft;
                     println!("{}", "✓ Draft loaded.\n".green());
                 }
             } else {
-                TaskDraft::clear()?;
+                TaskDraft::clear(&self.config)?;
             }
         }
 
         // Step 1: 基本信息
         if self.draft.title.is_none() {
             self.collect_basic_info().await?;
-            self.draft.save()?;
+            self.draft.save(&self.config)?;
         }
 
         // Step 2: 任务描述
         if self.draft.description.is_none() {
             self.collect_description().await?;
-            self.draft.save()?;
+            self.draft.save(&self.config)?;
         }
 
         // Step 3: 预算设置
         if self.draft.budget_min.is_none() && self.draft.budget_max.is_none() {
             self.collect_budget().await?;
-            self.draft.save()?;
+            self.draft.save(&self.config)?;
         }
 
         // Step 4: 工作设置
         if self.draft.location_type.is_none() {
             self.collect_work_settings().await?;
-            self.draft.save()?;
+            self.draft.save(&self.config)?;
         }
 
         // Step 5: 技能选择
         self.collect_skills().await?;
-        self.draft.save()?;
+        self.draft.save(&self.config)?;
 
         // Step 6: 确认和发布
         let should_publish = self.review_and_confirm().await?;
 
         if should_publish {
             self.publish_task().await?;
-            TaskDraft::clear()?;
+            TaskDraft::clear(&self.config)?;
         } else {
-            self.draft.save()?;
+            self.draft.save(&self.config)?;
             println!("\n{}", "Draft saved. You can resume later.".yellow());
         }
 
@@ -369,9 +369,10 @@ impl TaskPublishWizard {
         println!("{}", "\n🎯 Step 5: Required Skills\n".bold());
 
         let client = ApiClient::new(&self.config)?;
-        
-        match client.list_skills().await {
-            Ok(skills) => {
+
+        match SkillCatalog::load(&client, &self.config, false).await {
+            Ok(catalog) => {
+                let skills = catalog.skills();
                 if skills.is_empty() {
                     println!("{}", "No skills available in the catalog.".yellow());
                     return Ok(());
@@ -477,27 +478,27 @@ impl TaskPublishWizard {
                 2 => {
                     // Edit basic info
                     self.collect_basic_info().await?;
-                    self.draft.save()?;
+                    self.draft.save(&self.config)?;
                 }
                 3 => {
                     // Edit description
                     self.collect_description().await?;
-                    self.draft.save()?;
+                    self.draft.save(&self.config)?;
                 }
                 4 => {
                     // Edit budget
                     self.collect_budget().await?;
-                    self.draft.save()?;
+                    self.draft.save(&self.config)?;
                 }
                 5 => {
                     // Edit work settings
                     self.collect_work_settings().await?;
-                    self.draft.save()?;
+                    self.draft.save(&self.config)?;
                 }
                 6 => {
                     // Edit skills
                     self.collect_skills().await?;
-                    self.draft.save()?;
+                    self.draft.save(&self.config)?;
                 }
                 _ => unreachable!(),
             }
@@ -1,6 +1,6 @@
 use anyhow::{anyhow, Context, Result};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// CLI 配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +22,10 @@ pub struct Config {
     #[serde(default)]
     pub defaults: Defaults,
 
+    /// 收件箱设置
+    #[serde(default)]
+    pub inbox: InboxConfig,
+
     /// 运行时覆盖的 Agent API Key（不落盘）
     #[serde(skip)]
     pub runtime_api_key: Option<String>,
@@ -42,6 +46,13 @@ pub struct Defaults {
     pub page_size: u32,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct InboxConfig {
+    /// 收件箱中关注的新任务搜索词，未设置时不显示任务
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_query: Option<String>,
+}
+
 fn default_server_url() -> String {
     "https://beta-api.agentlink.chat/".to_string()
 }
@@ -65,6 +76,7 @@ impl Default for Config {
             websocket_url: default_websocket_url(),
             api_key: None,
             defaults: Defaults::default(),
+            inbox: InboxConfig::default(),
             runtime_api_key: None,
             config_path: None,
         }
@@ -119,6 +131,13 @@ impl Config {
         Ok(config_dir.join("agentlink").join("config.toml"))
     }
 
+    /// 获取本地状态文件路径（草稿、缓存等），与当前生效的配置文件位于同一目录
+    pub fn state_file_path(&self, file_name: &str) -> Result<PathBuf> {
+        let config_path = self.current_config_path()?;
+        let state_dir = config_path.parent().unwrap_or_else(|| Path::new(""));
+        Ok(state_dir.join(file_name))
+    }
+
     /// 获取当前生效配置文件路径
     pub fn current_config_path(&self) -> Result<PathBuf> {
         if let Some(path) = &self.config_path {
@@ -249,6 +268,18 @@ mod tests {
         assert_eq!(loaded.api_key, Some("sk_test_token".to_string()));
     }
 
+    #[test]
+    fn test_state_file_path_follows_custom_config_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("profile").join("config.toml");
+
+        let config = Config::load(Some(config_path.to_str().unwrap())).unwrap();
+        assert_eq!(
+            config.state_file_path("queue.json").unwrap(),
+            temp_dir.path().join("profile").join("queue.json")
+        );
+    }
+
     #[test]
     fn test_config_load_legacy_user_token_field() {
         let temp_dir = TempDir::new().unwrap();
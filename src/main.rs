@@ -10,8 +10,9 @@ mod utils;
 
 use commands::{
     agent::AgentCommands, api_key::ApiKeyCommands, config::ConfigCommands, feed::FeedCommands,
-    messages::MessageCommands, notifications::NotificationCommands, posts::PostCommands,
-    tasks::TaskCommands, update::UpdateCommands,
+    inbox::InboxArgs, index::IndexCommands, messages::MessageCommands,
+    notifications::NotificationCommands, posts::PostCommands, queue::QueueCommands,
+    search::SearchArgs, skills::SkillCommands, tasks::TaskCommands, update::UpdateCommands,
 };
 
 /// AgentLink CLI - 面向 AI Agent 的 AgentLink 命令行工具
@@ -76,6 +77,12 @@ enum Commands {
         command: TaskCommands,
     },
 
+    /// 技能目录
+    Skills {
+        #[command(subcommand)]
+        command: SkillCommands,
+    },
+
     /// 动态流管理
     Feed {
         #[command(subcommand)]
@@ -102,6 +109,30 @@ enum Commands {
         command: NotificationCommands,
     },
 
+    /// 定时发送队列（动态与消息）
+    Queue {
+        #[command(subcommand)]
+        command: QueueCommands,
+    },
+
+    /// 本地全文索引（消息、动态与任务）
+    Index {
+        #[command(subcommand)]
+        command: IndexCommands,
+    },
+
+    /// 收件箱：汇总未读会话、未读通知和新任务，按优先级排列
+    Inbox {
+        #[command(flatten)]
+        args: InboxArgs,
+    },
+
+    /// 在本地索引中全文搜索
+    Search {
+        #[command(flatten)]
+        args: SearchArgs,
+    },
+
     /// Agent 专属命令
     #[command(alias = "ag")]
     Agent {
@@ -174,6 +205,9 @@ async fn main() -> Result<()> {
         Commands::ApiKey { command } => commands::api_key::execute(command, &mut config).await,
         Commands::Config { command } => commands::config::execute(command, &mut config).await,
         Commands::Tasks { command } => commands::tasks::execute(command, &config, cli.format).await,
+        Commands::Skills { command } => {
+            commands::skills::execute(command, &config, cli.format).await
+        }
         Commands::Feed { command } => commands::feed::execute(command, &config, cli.format).await,
         Commands::Posts { command } => commands::posts::execute(command, &config, cli.format).await,
         Commands::Messages { command } => {
@@ -182,6 +216,10 @@ async fn main() -> Result<()> {
         Commands::Notifications { command } => {
             commands::notifications::execute(command, &config, cli.format).await
         }
+        Commands::Queue { command } => commands::queue::execute(command, &config, cli.format).await,
+        Commands::Index { command } => commands::index::execute(command, &config, cli.format).await,
+        Commands::Inbox { args } => commands::inbox::execute(args, &config, cli.format).await,
+        Commands::Search { args } => commands::search::execute(args, &config, cli.format),
         Commands::Agent { command } => commands::agent::execute(command, &config, cli.format).await,
         Commands::Completion { shell } => {
             let mut cmd = Cli::command();
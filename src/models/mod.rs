@@ -2,11 +2,12 @@ pub use agentlink_protocol::{ConversationType, MessageType};
 
 pub use agentlink_protocol::comment::{CommentResponse, CreateCommentRequest};
 pub use agentlink_protocol::message::{
-    ConversationResponse, CreateConversationRequest, ParticipantResponse, SendMessageRequest,
+    ConversationResponse, CreateConversationRequest, MessageResponse, ParticipantResponse,
+    SendMessageRequest,
 };
 pub use agentlink_protocol::social::{
     CreatePostRequest, PostResponse, PostListQuery,
 };
 // Note: Feed v2 types are imported directly where needed
-pub use agentlink_protocol::task::{CreateApplicationRequest, TaskResponse};
-pub use agentlink_protocol::user::UserResponse;
+pub use agentlink_protocol::task::{CreateApplicationRequest, TaskResponse, TaskStatus};
+pub use agentlink_protocol::user::{Skill, UserResponse};
@@ -0,0 +1,50 @@
+use anyhow::{Context, Result};
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+/// 构造通过系统 shell 执行的命令
+pub fn shell_command(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    }
+}
+
+/// 执行外部钩子命令：通过环境变量传递上下文，并可选地将 JSON 写入标准输入
+pub async fn run(command: &str, env: &[(&str, String)], stdin: Option<String>) -> Result<()> {
+    let mut cmd = shell_command(command);
+    cmd.stdin(if stdin.is_some() {
+        Stdio::piped()
+    } else {
+        Stdio::null()
+    });
+    for (key, value) in env {
+        cmd.env(key, value);
+    }
+
+    let mut child = cmd
+        .spawn()
+        .with_context(|| format!("Failed to run hook `{}`", command))?;
+
+    if let (Some(input), Some(mut pipe)) = (stdin, child.stdin.take()) {
+        // 钩子可能不读取标准输入，写入失败不视为错误
+        let _ = pipe.write_all(input.as_bytes()).await;
+    }
+
+    let status = child
+        .wait()
+        .await
+        .with_context(|| format!("Failed to wait for hook `{}`", command))?;
+
+    if !status.success() {
+        anyhow::bail!("Hook `{}` exited with {}", command, status);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use anyhow::{Context, Result};
+use std::io::Read;
+use std::path::Path;
+
+/// 正文来源：命令行参数（`-` 表示标准输入）、文件或编辑器
+pub struct TextSource<'a> {
+    pub inline: Option<String>,
+    pub file: Option<&'a Path>,
+    pub edit: bool,
+    /// 编辑器临时文件的扩展名，例如 `.md`
+    pub extension: &'a str,
+}
+
+/// 读取正文，多个来源同时指定时报错；结果去除首尾空白且不能为空
+pub fn read_text(source: TextSource<'_>, what: &str) -> Result<String> {
+    let specified = [source.inline.is_some(), source.file.is_some(), source.edit]
+        .iter()
+        .filter(|specified| **specified)
+        .count();
+    if specified > 1 {
+        anyhow::bail!(
+            "Provide the {} only once: inline, `-` for stdin, `--file` or `--edit`.",
+            what
+        );
+    }
+
+    let text = match (source.inline, source.file) {
+        (Some(inline), _) if inline == "-" => read_stdin(what)?,
+        (Some(inline), _) => inline,
+        (None, Some(path)) => std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {} from {:?}", what, path))?,
+        (None, None) if source.edit => dialoguer::Editor::new()
+            .extension(source.extension)
+            .edit("")?
+            .unwrap_or_default(),
+        (None, None) => anyhow::bail!(
+            "No {} given. Pass it inline, `-` for stdin, `--file <path>` or `--edit`.",
+            what
+        ),
+    };
+
+    let text = text.trim().to_string();
+    if text.is_empty() {
+        anyhow::bail!("The {} is empty.", what);
+    }
+    Ok(text)
+}
+
+fn read_stdin(what: &str) -> Result<String> {
+    let mut text = String::new();
+    std::io::stdin()
+        .read_to_string(&mut text)
+        .with_context(|| format!("Failed to read {} from stdin", what))?;
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inline(value: &str) -> TextSource<'static> {
+        TextSource {
+            inline: Some(value.to_string()),
+            file: None,
+            edit: false,
+            extension: ".md",
+        }
+    }
+
+    #[test]
+    fn test_read_text_trims_inline_value() {
+        assert_eq!(read_text(inline("  hello \n"), "content").unwrap(), "hello");
+    }
+
+    #[test]
+    fn test_read_text_reads_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("post.md");
+        std::fs::write(&path, "line one\n\nline two\n").unwrap();
+
+        let text = read_text(
+            TextSource {
+                inline: None,
+                file: Some(&path),
+                edit: false,
+                extension: ".md",
+            },
+            "content",
+        )
+        .unwrap();
+        assert_eq!(text, "line one\n\nline two");
+    }
+
+    #[test]
+    fn test_read_text_rejects_multiple_sources_and_empty_text() {
+        let path = Path::new("post.md");
+        let both = TextSource {
+            inline: Some("x".to_string()),
+            file: Some(path),
+            edit: false,
+            extension: ".md",
+        };
+        assert!(read_text(both, "content").is_err());
+        assert!(read_text(inline("   "), "content").is_err());
+    }
+}
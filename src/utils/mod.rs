@@ -1 +1,6 @@
+pub mod hook;
+pub mod input;
 pub mod output;
+pub mod state;
+pub mod template;
+pub mod time;
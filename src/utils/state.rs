@@ -0,0 +1,95 @@
+use anyhow::{Context, Result};
+use serde::{de::DeserializeOwned, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+
+/// 读取配置目录下的 JSON 状态文件；文件不存在时返回默认值
+pub fn load<T>(config: &Config, file_name: &str) -> Result<T>
+where
+    T: DeserializeOwned + Default,
+{
+    load_from(&config.state_file_path(file_name)?)
+}
+
+/// 写入配置目录下的 JSON 状态文件
+pub fn save<T>(config: &Config, file_name: &str, value: &T) -> Result<()>
+where
+    T: Serialize,
+{
+    save_to(&config.state_file_path(file_name)?, value)
+}
+
+pub fn load_from<T>(path: &Path) -> Result<T>
+where
+    T: DeserializeOwned + Default,
+{
+    if !path.exists() {
+        return Ok(T::default());
+    }
+
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read state file: {:?}", path))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse state file: {:?}", path))
+}
+
+pub fn save_to<T>(path: &Path, value: &T) -> Result<()>
+where
+    T: Serialize,
+{
+    write_atomically(path, &serde_json::to_string_pretty(value)?)
+}
+
+/// 先写临时文件再重命名，避免中断时留下半截文件
+pub fn write_atomically(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {:?}", parent))?;
+    }
+
+    let mut temp_path = PathBuf::from(path);
+    temp_path.as_mut_os_string().push(".tmp");
+
+    std::fs::write(&temp_path, content)
+        .with_context(|| format!("Failed to write file: {:?}", temp_path))?;
+    std::fs::rename(&temp_path, path)
+        .with_context(|| format!("Failed to write file: {:?}", path))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_missing_state_returns_default() {
+        let temp_dir = TempDir::new().unwrap();
+        let state: BTreeMap<String, u32> =
+            load_from(&temp_dir.path().join("missing.json")).unwrap();
+        assert!(state.is_empty());
+    }
+
+    #[test]
+    fn test_save_and_load_state_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("nested").join("state.json");
+
+        let mut state = BTreeMap::new();
+        state.insert("cursor".to_string(), 42_u32);
+        save_to(&path, &state).unwrap();
+
+        let loaded: BTreeMap<String, u32> = load_from(&path).unwrap();
+        assert_eq!(loaded, state);
+        assert!(!temp_dir
+            .path()
+            .join("nested")
+            .join("state.json.tmp")
+            .exists());
+    }
+}
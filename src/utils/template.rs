@@ -0,0 +1,64 @@
+/// 渲染 `{name}` 形式的占位符；未知占位符保持原样
+///
+/// 只扫描一遍模板，替换进来的值即使包含 `{name}` 也不会被再次展开
+pub fn render(template: &str, values: &[(&str, String)]) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let replacement = after.find('}').and_then(|end| {
+            values
+                .iter()
+                .find(|(key, _)| *key == &after[..end])
+                .map(|(_, value)| (end, value))
+        });
+
+        match replacement {
+            Some((end, value)) => {
+                rendered.push_str(value);
+                rest = &after[end + 1..];
+            }
+            None => {
+                rendered.push('{');
+                rest = after;
+            }
+        }
+    }
+
+    rendered.push_str(rest);
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_replaces_known_placeholders() {
+        let rendered = render(
+            "Reminder: {title} is {countdown} ({unknown})",
+            &[
+                ("title", "Ship report".to_string()),
+                ("countdown", "due in 1h 0m".to_string()),
+            ],
+        );
+        assert_eq!(
+            rendered,
+            "Reminder: Ship report is due in 1h 0m ({unknown})"
+        );
+    }
+
+    #[test]
+    fn test_render_does_not_expand_placeholders_inside_values() {
+        let rendered = render(
+            "{title}: {countdown} {{title}",
+            &[
+                ("title", "Fix {countdown}".to_string()),
+                ("countdown", "due in 1h".to_string()),
+            ],
+        );
+        assert_eq!(rendered, "Fix {countdown}: due in 1h {Fix {countdown}");
+    }
+}
@@ -0,0 +1,207 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use std::time::Duration;
+
+/// `parse_datetime` 接受的本地时间格式
+const LOCAL_DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+];
+
+/// 解析简写时长，例如 `30s`、`5m`、`24h`、`7d`、`1h30m`
+pub fn parse_duration(value: &str) -> Result<Duration> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(anyhow!("Duration must not be empty"));
+    }
+
+    // 纯数字视为秒
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Ok(Duration::from_secs(seconds));
+    }
+
+    let mut total = 0_u64;
+    let mut digits = String::new();
+
+    for ch in value.chars() {
+        if ch.is_ascii_digit() {
+            digits.push(ch);
+            continue;
+        }
+
+        let amount: u64 = digits
+            .parse()
+            .map_err(|_| anyhow!("Invalid duration `{}`", value))?;
+        digits.clear();
+
+        let unit = match ch.to_ascii_lowercase() {
+            's' => 1,
+            'm' => 60,
+            'h' => 60 * 60,
+            'd' => 24 * 60 * 60,
+            'w' => 7 * 24 * 60 * 60,
+            _ => {
+                return Err(anyhow!(
+                    "Invalid duration unit `{}` in `{}` (use s, m, h, d or w)",
+                    ch,
+                    value
+                ))
+            }
+        };
+
+        total = amount
+            .checked_mul(unit)
+            .and_then(|seconds| total.checked_add(seconds))
+            .ok_or_else(|| anyhow!("Duration `{}` is too large", value))?;
+    }
+
+    if !digits.is_empty() {
+        return Err(anyhow!("Duration `{}` is missing a unit", value));
+    }
+
+    Ok(Duration::from_secs(total))
+}
+
+/// 解析轮询间隔；与 `parse_duration` 相同，但拒绝 0，避免监听循环空转
+pub fn parse_interval(value: &str) -> Result<Duration> {
+    let interval = parse_duration(value)?;
+    if interval.is_zero() {
+        return Err(anyhow!("Interval must be greater than zero"));
+    }
+    Ok(interval)
+}
+
+/// 解析时间点：RFC 3339、本地时间（`2026-11-01 09:00`、`2026-11-01`）或相对时间（`+2h`、`-24h`）
+pub fn parse_datetime(value: &str) -> Result<DateTime<Utc>> {
+    let value = value.trim();
+
+    if let Some(relative) = value.strip_prefix('+') {
+        let offset = chrono::Duration::from_std(parse_duration(relative)?)
+            .map_err(|_| anyhow!("Time offset `{}` is too large", value))?;
+        return Ok(Utc::now() + offset);
+    }
+    if let Some(relative) = value.strip_prefix('-') {
+        let offset = chrono::Duration::from_std(parse_duration(relative)?)
+            .map_err(|_| anyhow!("Time offset `{}` is too large", value))?;
+        return Ok(Utc::now() - offset);
+    }
+
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(time.with_timezone(&Utc));
+    }
+
+    let naive = LOCAL_DATETIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .ok_or_else(|| {
+            anyhow!(
+                "Invalid time `{}` (use `2026-11-01 09:00`, RFC 3339, `+2h` or `-24h`)",
+                value
+            )
+        })?;
+
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|time| time.with_timezone(&Utc))
+        .ok_or_else(|| anyhow!("Time `{}` does not exist in the local timezone", value))
+}
+
+/// 将时长格式化为紧凑形式，例如 `2d 4h`、`1h 30m`、`45m`
+pub fn format_duration_short(duration: chrono::Duration) -> String {
+    let minutes = duration.num_minutes().abs();
+    let days = minutes / (24 * 60);
+    let hours = (minutes % (24 * 60)) / 60;
+    let mins = minutes % 60;
+
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, mins)
+    } else {
+        format!("{}m", mins)
+    }
+}
+
+/// 等待指定时长；若期间收到 Ctrl+C 则返回 `false`
+pub async fn sleep_or_interrupt(duration: Duration) -> bool {
+    tokio::select! {
+        _ = tokio::time::sleep(duration) => true,
+        _ = tokio::signal::ctrl_c() => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration_units() {
+        assert_eq!(parse_duration("45").unwrap(), Duration::from_secs(45));
+        assert_eq!(parse_duration("30s").unwrap(), Duration::from_secs(30));
+        assert_eq!(parse_duration("5m").unwrap(), Duration::from_secs(300));
+        assert_eq!(parse_duration("24h").unwrap(), Duration::from_secs(86_400));
+        assert_eq!(parse_duration("1h30m").unwrap(), Duration::from_secs(5_400));
+        assert_eq!(parse_duration("1w").unwrap(), Duration::from_secs(604_800));
+    }
+
+    #[test]
+    fn test_parse_duration_rejects_invalid_values() {
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("h").is_err());
+        assert!(parse_duration("10x").is_err());
+        assert!(parse_duration("1h30").is_err());
+    }
+
+    #[test]
+    fn test_parse_interval_rejects_zero() {
+        assert!(parse_interval("0").is_err());
+        assert!(parse_interval("0s").is_err());
+        assert_eq!(parse_interval("30s").unwrap(), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_parse_datetime_formats() {
+        assert_eq!(
+            parse_datetime("2026-11-01T09:00:00Z").unwrap(),
+            Utc.with_ymd_and_hms(2026, 11, 1, 9, 0, 0).unwrap()
+        );
+
+        let local = Local
+            .with_ymd_and_hms(2026, 11, 1, 9, 0, 0)
+            .earliest()
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(parse_datetime("2026-11-01 09:00").unwrap(), local);
+
+        let relative = parse_datetime("+2h").unwrap() - Utc::now();
+        assert!(relative > chrono::Duration::minutes(119));
+        assert!(relative <= chrono::Duration::hours(2));
+
+        let ago = Utc::now() - parse_datetime("-1d").unwrap();
+        assert!(ago >= chrono::Duration::hours(24));
+        assert!(ago < chrono::Duration::hours(25));
+
+        assert!(parse_datetime("next tuesday").is_err());
+    }
+
+    #[test]
+    fn test_format_duration_short() {
+        assert_eq!(
+            format_duration_short(chrono::Duration::minutes(2 * 24 * 60 + 4 * 60 + 10)),
+            "2d 4h"
+        );
+        assert_eq!(
+            format_duration_short(chrono::Duration::minutes(-90)),
+            "1h 30m"
+        );
+        assert_eq!(format_duration_short(chrono::Duration::minutes(7)), "7m");
+    }
+}
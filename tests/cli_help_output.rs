@@ -24,3 +24,39 @@ fn api_key_help_describes_local_key_management() {
         .stdout(predicate::str::contains("保存 Agent API Key"))
         .stdout(predicate::str::contains("校验当前 API Key"));
 }
+
+#[test]
+fn search_help_lists_filters() {
+    let mut cmd = Command::cargo_bin("agentlink").unwrap();
+    cmd.args(["search", "--help"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--type"))
+        .stdout(predicate::str::contains("--conversation"))
+        .stdout(predicate::str::contains("--since"));
+}
+
+#[test]
+fn inbox_help_lists_agent_options() {
+    let mut cmd = Command::cargo_bin("agentlink").unwrap();
+    cmd.args(["inbox", "--help"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--json"))
+        .stdout(predicate::str::contains("--watch"))
+        .stdout(predicate::str::contains("--tasks"));
+}
+
+#[test]
+fn notifications_route_help_lists_daemon_options() {
+    let mut cmd = Command::cargo_bin("agentlink").unwrap();
+    cmd.args(["notifications", "route", "--help"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--config"))
+        .stdout(predicate::str::contains("--dry-run"))
+        .stdout(predicate::str::contains("--backlog"));
+}
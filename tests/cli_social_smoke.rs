@@ -1,4 +1,5 @@
 use assert_cmd::Command;
+use predicates::prelude::*;
 
 #[test]
 fn feed_list_parses_and_handles_unreachable_server() {
@@ -65,3 +66,164 @@ fn posts_comments_create_parses_parent_id_without_panic() {
 
     cmd.assert().success();
 }
+
+#[test]
+fn feed_tail_rejects_invalid_state_name() {
+    let mut cmd = Command::cargo_bin("agentlink").unwrap();
+    cmd.args([
+        "--api-key",
+        "sk_test",
+        "--base-url",
+        "http://127.0.0.1:9",
+        "feed",
+        "tail",
+        "--state",
+        "../escape",
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid state name"));
+}
+
+#[test]
+fn posts_create_reads_content_from_stdin() {
+    let mut cmd = Command::cargo_bin("agentlink").unwrap();
+    cmd.args([
+        "--api-key",
+        "sk_test",
+        "--base-url",
+        "http://127.0.0.1:9",
+        "posts",
+        "create",
+        "-",
+        "--media",
+        "https://example.com/a.png",
+        "--visibility",
+        "connections",
+    ])
+    .write_stdin("第一段\n\n第二段 \"带引号\"\n");
+
+    cmd.assert().success();
+}
+
+#[test]
+fn posts_create_rejects_unknown_visibility() {
+    let mut cmd = Command::cargo_bin("agentlink").unwrap();
+    cmd.args([
+        "--api-key",
+        "sk_test",
+        "posts",
+        "create",
+        "hello",
+        "--visibility",
+        "everyone",
+    ]);
+
+    cmd.assert().failure();
+}
+
+#[test]
+fn posts_comments_reply_requires_post_id() {
+    let mut cmd = Command::cargo_bin("agentlink").unwrap();
+    cmd.args([
+        "--api-key",
+        "sk_test",
+        "posts",
+        "comments",
+        "reply",
+        "550e8400-e29b-41d4-a716-446655440000",
+        "收到",
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--post"));
+}
+
+#[test]
+fn posts_prune_requires_a_filter() {
+    let mut cmd = Command::cargo_bin("agentlink").unwrap();
+    cmd.args([
+        "--api-key",
+        "sk_test",
+        "--base-url",
+        "http://127.0.0.1:9",
+        "posts",
+        "prune",
+        "--dry-run",
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("No filters given"));
+}
+
+#[test]
+fn messages_send_rejects_invalid_metadata() {
+    let mut cmd = Command::cargo_bin("agentlink").unwrap();
+    cmd.args([
+        "--api-key",
+        "sk_test",
+        "--base-url",
+        "http://127.0.0.1:9",
+        "messages",
+        "send",
+        "conv-1",
+        "status update",
+        "--metadata",
+        "{not json",
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid JSON"));
+}
+
+#[test]
+fn messages_send_reads_body_from_stdin() {
+    let mut cmd = Command::cargo_bin("agentlink").unwrap();
+    cmd.args([
+        "--api-key",
+        "sk_test",
+        "--base-url",
+        "http://127.0.0.1:9",
+        "messages",
+        "send",
+        "conv-1",
+        "-",
+        "--metadata",
+        r#"{"status": "delivered"}"#,
+    ])
+    .write_stdin("交付完成\n");
+
+    cmd.assert().success();
+}
+
+#[test]
+fn notifications_mark_read_rejects_id_with_filters() {
+    let mut cmd = Command::cargo_bin("agentlink").unwrap();
+    cmd.args([
+        "--api-key",
+        "sk_test",
+        "notifications",
+        "mark-read",
+        "n1",
+        "--kind",
+        "system",
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn search_rejects_blank_query() {
+    let mut cmd = Command::cargo_bin("agentlink").unwrap();
+    cmd.args(["search", "   "]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("search query is empty"));
+}
@@ -44,3 +44,71 @@ fn tasks_list_can_access_beta_public_endpoint() {
                 .or(predicate::str::contains("Available Tasks")),
         );
 }
+
+#[test]
+fn tasks_apply_batch_dry_run_reads_csv_without_network() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("tasks.csv");
+    std::fs::write(
+        &input,
+        "task_id,budget,days\n550e8400-e29b-41d4-a716-446655440000,150,3\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("agentlink").unwrap();
+    cmd.args([
+        "--base-url",
+        "http://127.0.0.1:9",
+        "tasks",
+        "apply-batch",
+        "--input",
+        input.to_str().unwrap(),
+        "--dry-run",
+    ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "550e8400-e29b-41d4-a716-446655440000",
+        ))
+        .stdout(predicate::str::contains("No applications were submitted."));
+}
+
+#[test]
+fn tasks_remind_requires_a_delivery_target() {
+    let mut cmd = Command::cargo_bin("agentlink").unwrap();
+    cmd.args(["--api-key", "sk_test", "tasks", "remind", "--once"]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--exec"));
+}
+
+#[test]
+fn tasks_apply_batch_json_output_stays_parseable_and_fails_on_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("tasks.ndjson");
+    std::fs::write(
+        &input,
+        "{\"task_id\": \"550e8400-e29b-41d4-a716-446655440000\"}\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("agentlink").unwrap();
+    cmd.args([
+        "--api-key",
+        "sk_test",
+        "--base-url",
+        "http://127.0.0.1:9",
+        "--format",
+        "json",
+        "tasks",
+        "apply-batch",
+        "--input",
+        input.to_str().unwrap(),
+    ]);
+
+    let output = cmd.assert().failure().get_output().stdout.clone();
+    let results: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(results[0]["success"], false);
+}